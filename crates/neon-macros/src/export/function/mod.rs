@@ -51,7 +51,12 @@ pub(super) fn export(meta: meta::Meta, input: syn::ItemFn) -> proc_macro::TokenS
     // Convert the result
     // N.B.: Braces are intentionally included to avoid leaking trait to function body
     let result_extract = quote::quote!({
-        use neon::macro_internal::{ToNeonMarker, #return_tag as NeonReturnTag};
+        use neon::macro_internal::{
+            NeonThrowViaIntoThrow, NeonThrowViaTryIntoJs, NeonThrowViaValue, NeonThrowWrap,
+            ToNeonMarker, #return_tag as NeonReturnTag,
+        };
+
+        let res = (&&&NeonThrowWrap(&res)).neon_throw_tag().neon_throw(&mut cx, res);
 
         (&res).to_neon_marker::<NeonReturnTag>().neon_into_js(&mut cx, res)
     });
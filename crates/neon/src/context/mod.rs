@@ -162,10 +162,11 @@
 pub(crate) mod internal;
 
 use std::{
+    any::Any,
     convert::Into,
     marker::PhantomData,
     ops::{Deref, DerefMut},
-    panic::UnwindSafe,
+    panic::{catch_unwind, AssertUnwindSafe, UnwindSafe},
 };
 
 pub use crate::types::buffer::lock::Lock;
@@ -181,8 +182,9 @@ use crate::{
     },
     types::{
         boxed::{Finalize, JsBox},
-        error::JsError,
-        extract::FromArgs,
+        build,
+        error::{JsError, SystemErrorBuilder},
+        extract::{FromArgs, TryFromJs},
         private::ValueInternal,
         Deferred, JsArray, JsArrayBuffer, JsBoolean, JsBuffer, JsFunction, JsNull, JsNumber,
         JsObject, JsPromise, JsString, JsUndefined, JsValue, StringResult, Value,
@@ -359,6 +361,15 @@ pub enum CallKind {
     Call,
 }
 
+/// An error caught by [`Context::try_catch_unwind`].
+#[derive(Debug)]
+pub enum CaughtError<'a> {
+    /// A JavaScript exception was thrown.
+    Throw(Handle<'a, JsValue>),
+    /// Rust code panicked, with the given payload.
+    Panic(Box<dyn Any + Send + 'static>),
+}
+
 /// An _execution context_, which represents the current state of a thread of execution in the JavaScript engine.
 ///
 /// All interaction with the JavaScript engine in Neon code is mediated through instances of this trait.
@@ -394,6 +405,66 @@ pub trait Context<'a>: ContextInternal<'a> {
         result
     }
 
+    /// Calls `f` once for each index in `0..iter_count`, opening a new memory management
+    /// scope only every `every` iterations, and collects the results.
+    ///
+    /// This is a batched alternative to calling [`execute_scoped`](Context::execute_scoped)
+    /// in a loop. Setting up and tearing down a scope on every iteration can dominate tight
+    /// loops that create many short-lived handles; sharing each scope across a batch of
+    /// iterations amortizes that cost.
+    ///
+    /// **Memory:** handles created during an iteration are not released when that iteration
+    /// ends, but only when its batch's scope closes. Up to `every` iterations' worth of
+    /// handles may be alive at once, so larger values trade memory for fewer scope
+    /// operations. An `every` of `1` behaves like `execute_scoped` in a loop.
+    ///
+    /// Handles cannot escape the computation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `every` is `0`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn sum_lengths(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let words: Handle<JsArray> = cx.argument(0)?;
+    ///     let len = words.len(&mut cx) as usize;
+    ///     let lengths = cx.execute_scoped_batched(64, len, |cx, i| {
+    ///         let word: Handle<JsString> = words.get(cx, i as u32)?;
+    ///
+    ///         Ok(word.size(cx) as f64)
+    ///     });
+    ///     let total = lengths.into_iter().sum::<NeonResult<f64>>()?;
+    ///
+    ///     Ok(cx.number(total))
+    /// }
+    /// ```
+    fn execute_scoped_batched<T, F>(&mut self, every: usize, iter_count: usize, mut f: F) -> Vec<T>
+    where
+        F: for<'b> FnMut(&mut Cx<'b>, usize) -> T,
+    {
+        assert!(every > 0, "`every` must be greater than zero");
+
+        let env = self.env();
+        let mut results = Vec::with_capacity(iter_count);
+        let mut start = 0;
+
+        while start < iter_count {
+            let end = iter_count.min(start.saturating_add(every));
+            let scope = unsafe { HandleScope::new(env.to_raw()) };
+            let mut cx = Cx::new(env);
+
+            for i in start..end {
+                results.push(f(&mut cx, i));
+            }
+
+            drop(scope);
+            start = end;
+        }
+
+        results
+    }
+
     /// Executes a computation in a new memory management scope and computes a single result value that outlives the computation.
     ///
     /// Handles created in the new scope are kept alive only for the duration of the computation and cannot escape, with the exception of the result value, which is rooted in the outer context.
@@ -416,6 +487,26 @@ pub trait Context<'a>: ContextInternal<'a> {
         }))
     }
 
+    /// Executes `f`, catching any JavaScript exception it throws and returning the
+    /// exception as the `Err` value.
+    ///
+    /// Only exceptions thrown synchronously while `f` runs are caught. In particular,
+    /// a promise created by `f` that later rejects is not reported, even if nothing
+    /// handles the rejection; Node-API does not expose the microtask queue or pending
+    /// rejections. Code driving asynchronous JavaScript should attach handlers to the
+    /// promises it receives instead.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn call_safely(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let f: Handle<JsFunction> = cx.argument(0)?;
+    ///
+    ///     match cx.try_catch(|cx| f.bind(cx).call::<Handle<JsValue>>()) {
+    ///         Ok(v) => Ok(v),
+    ///         Err(_err) => Ok(cx.undefined().upcast()),
+    ///     }
+    /// }
+    /// ```
     fn try_catch<T, F>(&mut self, f: F) -> Result<T, Handle<'a, JsValue>>
     where
         F: FnOnce(&mut Self) -> NeonResult<T>,
@@ -427,6 +518,57 @@ pub trait Context<'a>: ContextInternal<'a> {
         }
     }
 
+    /// Indicates whether a JavaScript exception is pending, i.e., whether an operation
+    /// has returned [`Throw`] without the exception having been caught.
+    ///
+    /// While an exception is pending, most operations on the JavaScript engine will
+    /// fail. This is useful for deciding whether to propagate an error or to throw a
+    /// new one, and for debug assertions in code that handles [`Throw`] values.
+    fn has_pending_exception(&mut self) -> bool {
+        unsafe { sys::error::is_throwing(self.env().to_raw()) }
+    }
+
+    /// Like [`try_catch`](Context::try_catch), but also catches Rust panics that unwind out
+    /// of the closure.
+    ///
+    /// Returns [`CaughtError::Throw`] with the exception if a JavaScript exception was thrown,
+    /// or [`CaughtError::Panic`] with the panic payload if `f` panicked. If `f` panicked while
+    /// a JavaScript exception was pending, the exception is discarded. In either case, the
+    /// context is restored to a non-throwing state.
+    ///
+    /// Any state captured by `f` may be left inconsistent by a panic, since `f` is not
+    /// required to be [`UnwindSafe`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::context::CaughtError;
+    ///
+    /// fn call_plugin(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let plugin: Handle<JsFunction> = cx.argument(0)?;
+    ///
+    ///     match cx.try_catch_unwind(|cx| plugin.bind(cx).call()) {
+    ///         Ok(v) => Ok(v),
+    ///         Err(CaughtError::Throw(err)) => cx.throw(err),
+    ///         Err(CaughtError::Panic(_)) => Ok(cx.undefined().upcast()),
+    ///     }
+    /// }
+    /// ```
+    fn try_catch_unwind<T, F>(&mut self, f: F) -> Result<T, CaughtError<'a>>
+    where
+        F: FnOnce(&mut Self) -> NeonResult<T>,
+    {
+        let env = self.env();
+
+        match catch_unwind(AssertUnwindSafe(|| unsafe { env.try_catch(|| f(self)) })) {
+            Ok(Ok(v)) => Ok(v),
+            Ok(Err(err)) => Err(CaughtError::Throw(JsValue::new_internal(err))),
+            Err(panic) => {
+                unsafe { sys::error::clear_exception(env.to_raw()) };
+                Err(CaughtError::Panic(panic))
+            }
+        }
+    }
+
     /// Convenience method for creating a `JsBoolean` value.
     fn boolean(&mut self, b: bool) -> Handle<'a, JsBoolean> {
         JsBoolean::new(self, b)
@@ -451,6 +593,13 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsString::try_new(self, s)
     }
 
+    /// Convenience method for creating a `JsString` value from UTF-16 code units.
+    ///
+    /// If the string exceeds the limits of the JS engine, this method returns an `Err` value.
+    fn string_utf16(&mut self, s: &[u16]) -> StringResult<'a> {
+        JsString::from_utf16(self, s)
+    }
+
     /// Convenience method for creating a `JsNull` value.
     fn null(&mut self) -> Handle<'a, JsNull> {
         JsNull::new(self)
@@ -487,6 +636,93 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsDate::new(self, value)
     }
 
+    /// Converts a value to a number with the semantics of the JavaScript expression
+    /// [`Number(v)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/Number).
+    ///
+    /// Unlike [`Handle::downcast`], this never fails because of the type of `v`; for
+    /// example, `"42"` coerces to `42` and `{}` to `NaN`. Coercion may call `valueOf`
+    /// or `toString` on objects and throws for symbols and `BigInt`s.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn add_one(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let v = cx.argument::<JsValue>(0)?;
+    ///     let n = cx.coerce_to_number(v)?.value(&mut cx);
+    ///
+    ///     Ok(cx.number(n + 1.0))
+    /// }
+    /// ```
+    fn coerce_to_number<V: Value>(&mut self, v: Handle<V>) -> JsResult<'a, JsNumber> {
+        let env = self.env();
+
+        build(env, |out| unsafe {
+            sys::convert::to_number(out, env.to_raw(), v.to_local())
+        })
+    }
+
+    /// Converts a value to a string with the semantics of the JavaScript expression
+    /// [`String(v)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/String).
+    ///
+    /// Coercion may call `toString` or `valueOf` on objects and throws for symbols.
+    fn coerce_to_string<V: Value>(&mut self, v: Handle<V>) -> JsResult<'a, JsString> {
+        let env = self.env();
+
+        build(env, |out| unsafe {
+            sys::convert::to_string(out, env.to_raw(), v.to_local())
+        })
+    }
+
+    /// Converts a value to a boolean with the semantics of the JavaScript expression
+    /// [`Boolean(v)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Boolean/Boolean),
+    /// i.e., whether `v` is
+    /// [truthy](https://developer.mozilla.org/en-US/docs/Glossary/Truthy).
+    fn coerce_to_boolean<V: Value>(&mut self, v: Handle<V>) -> JsResult<'a, JsBoolean> {
+        let env = self.env();
+
+        build(env, |out| unsafe {
+            sys::convert::to_boolean(out, env.to_raw(), v.to_local())
+        })
+    }
+
+    /// Parses `s` as an integer in base `radix`, equivalent to the JavaScript expression
+    /// [`parseInt(s, radix)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/parseInt).
+    ///
+    /// As in JavaScript, leading whitespace is ignored, parsing stops at the first
+    /// invalid character, and the result is `NaN` if no digits could be parsed. A
+    /// `radix` of `0` infers the base from the string, accepting a `0x` prefix for
+    /// hexadecimal.
+    ///
+    /// With Node-API 6 or later, the `parseInt` global is looked up once per instance
+    /// of the addon.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn parse_hex(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let s: String = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     cx.parse_int(&s, 16)
+    /// }
+    /// ```
+    fn parse_int(&mut self, s: &str, radix: u32) -> JsResult<'a, JsNumber> {
+        let cx = self.cx_mut();
+
+        parse_int_function(cx)?.bind(cx).arg(s)?.arg(radix)?.call()
+    }
+
+    /// Parses `s` as a floating point number, equivalent to the JavaScript expression
+    /// [`parseFloat(s)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/parseFloat).
+    ///
+    /// As in JavaScript, leading whitespace is ignored, parsing stops at the first
+    /// invalid character, and the result is `NaN` if no number could be parsed.
+    ///
+    /// With Node-API 6 or later, the `parseFloat` global is looked up once per instance
+    /// of the addon.
+    fn parse_float(&mut self, s: &str) -> JsResult<'a, JsNumber> {
+        let cx = self.cx_mut();
+
+        parse_float_function(cx)?.bind(cx).arg(s)?.call()
+    }
+
     /// Convenience method for looking up a global property by name.
     ///
     /// Equivalent to:
@@ -516,6 +752,27 @@ pub trait Context<'a>: ContextInternal<'a> {
         })
     }
 
+    /// Produces a handle to the current value of the JavaScript
+    /// [`globalThis`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/globalThis)
+    /// binding.
+    ///
+    /// In Node.js and worker threads this is normally the same object returned by
+    /// [`global_object`](Context::global_object), which is the global object of the
+    /// context the addon was loaded in. However, `globalThis` is an ordinary writable
+    /// property, and some embeddings (for example, Electron isolated worlds or `vm`
+    /// sandboxes that install their own binding) expose a different object under this
+    /// name. Use this method when the intent is to observe exactly what JavaScript code
+    /// sees as `globalThis`.
+    ///
+    /// Throws a `TypeError` if `globalThis` has been replaced with a value that is not
+    /// an object.
+    fn global_this(&mut self) -> JsResult<'a, JsObject> {
+        let global = self.global_object();
+        let value: Handle<JsValue> = global.get(self, "globalThis")?;
+
+        value.downcast_or_throw(self)
+    }
+
     /// Throws a JS value.
     fn throw<T: Value, U>(&mut self, v: Handle<T>) -> NeonResult<U> {
         unsafe {
@@ -529,6 +786,41 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsError::error(self, msg)
     }
 
+    /// Creates a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// with its [`code`](https://nodejs.org/api/errors.html#errorcode) property set.
+    fn error_with_code<S: AsRef<str>, M: AsRef<str>>(
+        &mut self,
+        code: S,
+        msg: M,
+    ) -> JsResult<'a, JsError> {
+        JsError::error_with_code(self, code, msg)
+    }
+
+    /// Creates a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// with its [`cause`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error/cause) property set.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn load_config(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let load: Handle<JsFunction> = cx.argument(0)?;
+    ///
+    ///     match cx.try_catch(|cx| load.bind(cx).call()) {
+    ///         Ok(config) => Ok(config),
+    ///         Err(cause) => {
+    ///             let err = cx.error_with_cause("failed to load config", cause)?;
+    ///             cx.throw(err)
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn error_with_cause<S: AsRef<str>, V: Value>(
+        &mut self,
+        msg: S,
+        cause: Handle<'a, V>,
+    ) -> JsResult<'a, JsError> {
+        JsError::error_with_cause(self, msg, cause)
+    }
+
     /// Creates an instance of the [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError) class.
     fn type_error<S: AsRef<str>>(&mut self, msg: S) -> JsResult<'a, JsError> {
         JsError::type_error(self, msg)
@@ -545,6 +837,29 @@ pub trait Context<'a>: ContextInternal<'a> {
         self.throw(err)
     }
 
+    /// Throws a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// with its [`code`](https://nodejs.org/api/errors.html#errorcode) property set.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn parse_port(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let port = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     match port.parse::<u16>() {
+    ///         Ok(port) => Ok(cx.number(port)),
+    ///         Err(_) => cx.throw_error_with_code("ERR_INVALID_ARG_VALUE", "invalid port"),
+    ///     }
+    /// }
+    /// ```
+    fn throw_error_with_code<S: AsRef<str>, M: AsRef<str>, T>(
+        &mut self,
+        code: S,
+        msg: M,
+    ) -> NeonResult<T> {
+        let err = JsError::error_with_code(self, code, msg)?;
+        self.throw(err)
+    }
+
     /// Throws an instance of the [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError) class.
     fn throw_type_error<S: AsRef<str>, T>(&mut self, msg: S) -> NeonResult<T> {
         let err = JsError::type_error(self, msg)?;
@@ -557,6 +872,70 @@ pub trait Context<'a>: ContextInternal<'a> {
         self.throw(err)
     }
 
+    /// Creates a [`SystemErrorBuilder`] for an error shaped like a Node.js
+    /// [`SystemError`](https://nodejs.org/api/errors.html#class-systemerror), with the
+    /// given `code` (e.g. `"ENOENT"`) and description.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn rename(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// cx.system_error("EXDEV", "cross-device link not permitted")
+    ///     .errno(-18)
+    ///     .syscall("rename")
+    ///     .path("/tmp/a")
+    ///     .dest("/home/b")
+    ///     .throw()
+    /// # }
+    /// ```
+    fn system_error<'cx, S: AsRef<str>, M: AsRef<str>>(
+        &'cx mut self,
+        code: S,
+        msg: M,
+    ) -> SystemErrorBuilder<'cx, Self>
+    where
+        'a: 'cx,
+    {
+        let code = code.as_ref().to_string();
+        let msg = msg.as_ref().to_string();
+
+        SystemErrorBuilder::new(self, code, msg)
+    }
+
+    /// Captures the JavaScript stack trace of the current call site.
+    ///
+    /// The trace is read from the
+    /// [`stack`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error/stack)
+    /// property of a newly constructed `Error`, with the leading `Error` header line
+    /// removed, so each line describes one JavaScript frame in the format of the engine,
+    /// starting with the JavaScript code that called into Rust. It reflects only the
+    /// JavaScript stack: Rust frames do not appear in it, and its depth is limited by
+    /// [`Error.stackTraceLimit`](https://nodejs.org/api/errors.html#errorstacktracelimit).
+    ///
+    /// Throws if the `stack` property is not a string, for example when it has been
+    /// customized with `Error.prepareStackTrace`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn log_caller(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let stack = cx.capture_stack()?;
+    ///
+    ///     eprintln!("called from:\n{stack}");
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    fn capture_stack(&mut self) -> NeonResult<String> {
+        let err = JsError::error(self, "")?;
+        let stack: String = err.prop(self.cx_mut(), "stack").get()?;
+
+        Ok(match stack.split_once('\n') {
+            Some((_, frames)) => frames.to_string(),
+            None => String::new(),
+        })
+    }
+
     /// Convenience method for wrapping a value in a `JsBox`.
     ///
     /// # Example:
@@ -600,6 +979,58 @@ pub trait Context<'a>: ContextInternal<'a> {
         channel
     }
 
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Returns a reference to the value stored with
+    /// [`set_instance_data`](Context::set_instance_data) for this instance of the addon.
+    ///
+    /// Returns `None` if no value has been set, or if the stored value is not of type `T`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// struct Config {
+    ///     verbose: bool,
+    /// }
+    ///
+    /// fn init(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let verbose = cx.argument::<JsBoolean>(0)?.value(&mut cx);
+    ///
+    ///     cx.set_instance_data(Config { verbose });
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    ///
+    /// fn is_verbose(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    ///     let verbose = cx
+    ///         .instance_data::<Config>()
+    ///         .map(|config| config.verbose)
+    ///         .unwrap_or(false);
+    ///
+    ///     Ok(cx.boolean(verbose))
+    /// }
+    /// ```
+    fn instance_data<T: Any + Send + 'static>(&mut self) -> Option<&T> {
+        InstanceData::user_data(self)
+            .as_ref()
+            .and_then(|data| data.downcast_ref())
+    }
+
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Stores `value` as user-defined data for this instance of the addon, replacing
+    /// and dropping any previously stored value.
+    ///
+    /// Each instance of the addon (e.g., the main thread and each worker thread) has
+    /// its own independent data. The value is dropped on the JavaScript thread when the
+    /// instance's environment is torn down, such as when a worker thread terminates or
+    /// the process exits normally.
+    ///
+    /// Only a single value can be stored per instance. Use a [`LocalKey`](crate::thread::LocalKey)
+    /// for multiple independent values.
+    fn set_instance_data<T: Any + Send + 'static>(&mut self, value: T) {
+        *InstanceData::user_data(self) = Some(Box::new(value));
+    }
+
     /// Creates a [`Deferred`] and [`JsPromise`] pair. The [`Deferred`] handle can be
     /// used to resolve or reject the [`JsPromise`].
     ///
@@ -651,6 +1082,58 @@ pub trait Context<'a>: ContextInternal<'a> {
     }
 }
 
+// Defines a function returning a built-in JavaScript function, either the global
+// `$global` or the function returned by the `$lookup` closure, e.g. a method of a
+// built-in prototype.
+//
+// User code may replace globals and built-in methods, e.g. `globalThis.Map` or
+// `Array.prototype.push`, and Neon should keep using the original functions. With
+// Node-API >= 6 the function is looked up once per instance of the addon, so later
+// changes do not affect it. Older versions cannot store per-instance data and look up
+// the function on every call.
+macro_rules! global_function {
+    ($name:ident, $global:literal) => {
+        $crate::context::global_function!($name, |cx| $crate::context::Context::global(
+            cx, $global
+        ));
+    };
+    ($name:ident, $lookup:expr) => {
+        #[cfg(not(feature = "napi-6"))]
+        fn $name<'cx>(
+            cx: &mut $crate::context::Cx<'cx>,
+        ) -> $crate::result::JsResult<'cx, $crate::types::JsFunction> {
+            let lookup: fn(
+                &mut $crate::context::Cx<'cx>,
+            ) -> $crate::result::JsResult<'cx, $crate::types::JsFunction> = $lookup;
+
+            lookup(cx)
+        }
+
+        #[cfg(feature = "napi-6")]
+        fn $name<'cx>(
+            cx: &mut $crate::context::Cx<'cx>,
+        ) -> $crate::result::JsResult<'cx, $crate::types::JsFunction> {
+            use $crate::{
+                context::Cx, handle::Root, object::Object, result::JsResult, thread::LocalKey,
+                types::JsFunction,
+            };
+
+            static FUNCTION: LocalKey<Root<JsFunction>> = LocalKey::new();
+
+            let lookup: fn(&mut Cx<'cx>) -> JsResult<'cx, JsFunction> = $lookup;
+
+            FUNCTION
+                .get_or_try_init(cx, |cx| lookup(cx).map(|f| f.root(cx)))
+                .map(|f| f.to_inner(cx))
+        }
+    };
+}
+
+pub(crate) use global_function;
+
+global_function!(parse_int_function, "parseInt");
+global_function!(parse_float_function, "parseFloat");
+
 /// An execution context of module initialization.
 pub struct ModuleContext<'cx> {
     cx: Cx<'cx>,
@@ -789,17 +1272,56 @@ impl<'cx> FunctionContext<'cx> {
         self.len() == 0
     }
 
+    fn arguments(&mut self) -> &sys::call::Arguments {
+        if self.arguments.is_none() {
+            self.arguments = Some(self.info.argv(self));
+        }
+
+        self.arguments.as_ref().unwrap()
+    }
+
     /// Produces the `i`th argument, or `None` if `i` is greater than or equal to `self.len()`.
     pub fn argument_opt(&mut self, i: usize) -> Option<Handle<'cx, JsValue>> {
-        let argv = if let Some(argv) = self.arguments.as_ref() {
-            argv
-        } else {
-            let argv = self.info.argv(self);
-            self.arguments.insert(argv)
-        };
+        let env = self.env();
 
-        argv.get(i)
-            .map(|v| Handle::new_internal(unsafe { JsValue::from_local(self.env(), v) }))
+        self.arguments()
+            .get(i)
+            .map(|v| Handle::new_internal(unsafe { JsValue::from_local(env, v) }))
+    }
+
+    /// Produces all of the arguments passed to the function, in order.
+    ///
+    /// This is convenient for forwarding the arguments to another function:
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn log_all(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let console: Handle<JsObject> = cx.global("console")?;
+    ///     let log: Handle<JsFunction> = console.get(&mut cx, "log")?;
+    ///     let args = cx.arguments_vec();
+    ///
+    ///     log.call(&mut cx, console, args)
+    /// }
+    /// ```
+    pub fn arguments_vec(&mut self) -> Vec<Handle<'cx, JsValue>> {
+        self.argument_slice(0, usize::MAX)
+    }
+
+    /// Produces the arguments with indices in the range `start..end`.
+    ///
+    /// Like [`argument_opt`](FunctionContext::argument_opt), indices past the last argument
+    /// are not an error: the range is truncated to the arguments that were passed, and an
+    /// empty `Vec` is returned if `start` is not less than `end` or `self.len()`.
+    pub fn argument_slice(&mut self, start: usize, end: usize) -> Vec<Handle<'cx, JsValue>> {
+        let env = self.env();
+        let argv = self.arguments().as_slice();
+        let end = end.min(argv.len());
+        let start = start.min(end);
+
+        argv[start..end]
+            .iter()
+            .map(|&v| Handle::new_internal(unsafe { JsValue::from_local(env, v) }))
+            .collect()
     }
 
     /// Produces the `i`th argument and casts it to the type `V`, or throws an exception if `i` is greater than or equal to `self.len()` or cannot be cast to `V`.
@@ -868,6 +1390,35 @@ impl<'cx> FunctionContext<'cx> {
         T::from_args_opt(self)
     }
 
+    /// Extract Rust data from both the [`this`-binding](FunctionContext::this_value)
+    /// and the JavaScript arguments.
+    ///
+    /// The `this`-binding is extracted first, followed by the arguments as with
+    /// [`FunctionContext::args`]. This is convenient for functions that are called
+    /// as methods.
+    ///
+    /// ```
+    /// # use neon::{prelude::*, types::extract::*};
+    /// fn set_name(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let (this, (first, last)): (Handle<JsObject>, (String, String)) =
+    ///         cx.args_with_this()?;
+    ///
+    ///     this.prop(&mut cx, "name").set(format!("{first} {last}"))?;
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn args_with_this<T, A>(&mut self) -> NeonResult<(T, A)>
+    where
+        T: TryFromJs<'cx>,
+        A: FromArgs<'cx>,
+    {
+        let this = self.this_value();
+        let this = T::from_js(self, this)?;
+
+        Ok((this, A::from_args(self)?))
+    }
+
     pub(crate) fn argv<const N: usize>(&mut self) -> [Handle<'cx, JsValue>; N] {
         self.info.argv_exact(self)
     }
@@ -4,12 +4,18 @@ use std::{
         atomic::{AtomicUsize, Ordering},
         Arc,
     },
+    thread::{self, ThreadId},
 };
 
 use crate::{
     context::{internal::Env, Context, Cx},
+    handle::Root,
     result::{NeonResult, ResultExt, Throw},
-    sys::{self, tsfn::ThreadsafeFunction},
+    sys::{
+        self,
+        tsfn::{CallError, ThreadsafeFunction},
+    },
+    types::{function::TryIntoArguments, JsFunction},
 };
 
 #[cfg(feature = "futures")]
@@ -113,27 +119,52 @@ impl Channel {
     /// Allow the Node event loop to exit while this `Channel` exists.
     /// _Idempotent_
     pub fn unref<'a, C: Context<'a>>(&mut self, cx: &mut C) -> &mut Self {
+        self.mark_unreferenced(cx);
+        self
+    }
+
+    /// Prevent the Node event loop from exiting while this `Channel` exists. (Default)
+    /// _Idempotent_
+    pub fn reference<'a, C: Context<'a>>(&mut self, cx: &mut C) -> &mut Self {
+        self.mark_referenced(cx);
+        self
+    }
+
+    /// Allow the Node event loop to exit while this `Channel` exists, like
+    /// [`Channel::unref`], returning `true` if the `Channel` was previously referenced.
+    /// _Idempotent_
+    ///
+    /// Clones of a `Channel` share a single Node-API threadsafe function and each
+    /// clone is referenced independently. The threadsafe function is only released with
+    /// [`napi_unref_threadsafe_function`](https://nodejs.org/api/n-api.html#napi_unref_threadsafe_function)
+    /// once every clone has been unreferenced or dropped.
+    pub fn mark_unreferenced<'a, C: Context<'a>>(&mut self, cx: &mut C) -> bool {
         // Already unreferenced
         if !self.has_ref {
-            return self;
+            return false;
         }
 
         self.has_ref = false;
         self.state.unref(cx);
-        self
+        true
     }
 
-    /// Prevent the Node event loop from exiting while this `Channel` exists. (Default)
+    /// Prevent the Node event loop from exiting while this `Channel` exists, like
+    /// [`Channel::reference`], returning `true` if the `Channel` was previously
+    /// referenced.
     /// _Idempotent_
-    pub fn reference<'a, C: Context<'a>>(&mut self, cx: &mut C) -> &mut Self {
+    ///
+    /// Referencing any clone of a `Channel` keeps the event loop alive with
+    /// [`napi_ref_threadsafe_function`](https://nodejs.org/api/n-api.html#napi_ref_threadsafe_function).
+    pub fn mark_referenced<'a, C: Context<'a>>(&mut self, cx: &mut C) -> bool {
         // Already referenced
         if self.has_ref {
-            return self;
+            return true;
         }
 
         self.has_ref = true;
         self.state.reference(cx);
-        self
+        false
     }
 
     /// Schedules a closure to execute on the JavaScript thread that created this Channel
@@ -151,6 +182,16 @@ impl Channel {
     ///
     /// See [`SendError`] for additional details on failure causes.
     pub fn try_send<T, F>(&self, f: F) -> Result<JoinHandle<T>, SendError>
+    where
+        T: Send + 'static,
+        F: FnOnce(Cx) -> NeonResult<T> + Send + 'static,
+    {
+        self.schedule(f).map_err(|_| SendError)
+    }
+
+    // Schedules a closure, preserving the underlying error for callers that need to
+    // distinguish an environment shutdown from other failures
+    pub(crate) fn schedule<T, F>(&self, f: F) -> Result<JoinHandle<T>, CallError>
     where
         T: Send + 'static,
         F: FnOnce(Cx) -> NeonResult<T> + Send + 'static,
@@ -167,19 +208,107 @@ impl Channel {
             });
         });
 
-        self.state
-            .tsfn
-            .call(callback, None)
-            .map_err(|_| SendError)?;
+        self.state.tsfn.call(callback, None)?;
 
         Ok(JoinHandle { rx })
     }
 
+    /// Schedules a call to a rooted JavaScript function on the JavaScript thread that
+    /// created this Channel. The `Root` is consumed and `callback` is called with
+    /// `undefined` as its `this` value and `args` as its arguments.
+    ///
+    /// This is a shortcut for the common pattern of calling back into JavaScript
+    /// from another thread with [`Channel::send`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn compute(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    ///     let channel = cx.channel();
+    ///
+    ///     std::thread::spawn(move || {
+    ///         let result = 6.0 * 7.0;
+    ///
+    ///         // Calls `callback(result)`
+    ///         channel.call_root(callback, (result,));
+    ///     });
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    ///
+    /// Panics if there is a libuv error
+    pub fn call_root<A>(&self, callback: Root<JsFunction>, args: A) -> JoinHandle<()>
+    where
+        A: for<'cx> TryIntoArguments<'cx> + Send + 'static,
+    {
+        self.send(move |mut cx| {
+            let callback = callback.into_inner(&mut cx);
+
+            callback.bind(&mut cx).args(args)?.exec()
+        })
+    }
+
+    /// Schedules a closure to execute on the JavaScript thread that created this Channel
+    /// and blocks the current thread until it completes, returning its result.
+    ///
+    /// This is a convenient alternative to [`Channel::send`] followed by
+    /// [`JoinHandle::join`] for plain threads that are not driven by an async runtime.
+    ///
+    /// Returns a [`JoinError`] if the closure could not be scheduled, or if it panicked
+    /// or threw an exception.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn example(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// let channel = cx.channel();
+    ///
+    /// std::thread::spawn(move || {
+    ///     let cwd = channel.send_and_wait(|mut cx| {
+    ///         let process: Handle<JsObject> = cx.global("process")?;
+    ///
+    ///         process.method(&mut cx, "cwd")?.call::<String>()
+    ///     });
+    ///
+    ///     println!("Working directory: {cwd:?}");
+    /// });
+    /// # Ok(cx.undefined())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method panics if called from the JavaScript thread that created this Channel,
+    /// since the closure could never execute while that thread is blocked. Like
+    /// [`JoinHandle::join`], it also panics if called within an asynchronous execution context.
+    pub fn send_and_wait<T, F>(&self, f: F) -> Result<T, JoinError>
+    where
+        T: Send + 'static,
+        F: FnOnce(Cx) -> NeonResult<T> + Send + 'static,
+    {
+        if thread::current().id() == self.state.thread {
+            panic!("`Channel::send_and_wait` cannot be called from the JavaScript thread");
+        }
+
+        self.try_send(f)
+            .map_err(|_| JoinError(JoinErrorType::Send))?
+            .join()
+    }
+
     /// Returns a boolean indicating if this `Channel` will prevent the Node event
     /// loop from exiting.
     pub fn has_ref(&self) -> bool {
         self.has_ref
     }
+
+    /// Returns a boolean indicating if this `Channel` will prevent the Node event
+    /// loop from exiting. Equivalent to [`Channel::has_ref`].
+    ///
+    /// Note that the event loop may still be kept alive by other referenced clones of
+    /// this `Channel`.
+    pub fn is_referenced(&self) -> bool {
+        self.has_ref
+    }
 }
 
 impl Clone for Channel {
@@ -291,12 +420,15 @@ impl<T> Future for JoinHandle<T> {
 #[derive(Debug)]
 /// Error returned by [`JoinHandle::join`] indicating the associated closure panicked
 /// or threw an exception.
+///
+/// Also returned by [`Channel::send_and_wait`] if the closure could not be scheduled.
 pub struct JoinError(JoinErrorType);
 
 #[derive(Debug)]
 enum JoinErrorType {
     Panic,
     Throw,
+    Send,
 }
 
 impl JoinError {
@@ -304,6 +436,7 @@ impl JoinError {
         match &self.0 {
             JoinErrorType::Panic => "Closure panicked before returning",
             JoinErrorType::Throw => "Closure threw an exception",
+            JoinErrorType::Send => "Closure could not be sent to the JavaScript thread",
         }
     }
 }
@@ -371,6 +504,8 @@ impl error::Error for SendError {}
 struct ChannelState {
     tsfn: ThreadsafeFunction<Callback>,
     ref_count: AtomicUsize,
+    // JavaScript thread that executes the scheduled closures
+    thread: ThreadId,
 }
 
 impl ChannelState {
@@ -379,6 +514,7 @@ impl ChannelState {
         Self {
             tsfn,
             ref_count: AtomicUsize::new(1),
+            thread: thread::current().id(),
         }
     }
 
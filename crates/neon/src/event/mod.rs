@@ -124,7 +124,7 @@ mod channel;
 
 mod task;
 
-pub use self::task::TaskBuilder;
+pub use self::task::{CancelToken, Cancellable, TaskBuilder};
 
 #[cfg(all(feature = "napi-5", feature = "futures"))]
 pub(crate) use self::channel::SendThrow;
@@ -1,4 +1,11 @@
-use std::{panic::resume_unwind, thread};
+use std::{
+    panic::resume_unwind,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+};
 
 use crate::{
     context::{internal::Env, Context, Cx},
@@ -27,6 +34,73 @@ pub struct TaskBuilder<'cx, C, E> {
     execute: E,
 }
 
+impl<'a: 'cx, 'cx, C> TaskBuilder<'cx, C, ()>
+where
+    C: Context<'a>,
+{
+    /// Construct a new task builder for a task that can be cancelled with the
+    /// returned [`CancelToken`].
+    ///
+    /// Cancellable tasks are scheduled with `promise` and do not provide `and_then`. If
+    /// the task is cancelled before `execute` starts running, `execute` and the
+    /// `complete` callback are skipped and the promise is rejected with an `Error`.
+    ///
+    /// Once `execute` has started, cancellation is cooperative: `execute` is passed the
+    /// token and may check [`CancelToken::is_cancelled`] to stop early.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::event::{CancelToken, TaskBuilder};
+    ///
+    /// fn count_primes(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let limit = cx.argument::<JsNumber>(0)?.value(&mut cx) as u64;
+    ///
+    ///     let (task, token) = TaskBuilder::with_cancellation(&mut cx, move |token: &CancelToken| {
+    ///         let mut count = 0;
+    ///
+    ///         for n in 2..limit {
+    ///             if token.is_cancelled() {
+    ///                 return None;
+    ///             }
+    ///
+    ///             if (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0) {
+    ///                 count += 1;
+    ///             }
+    ///         }
+    ///
+    ///         Some(count)
+    ///     });
+    ///
+    ///     // Cancel the task from another thread after a timeout
+    ///     std::thread::spawn(move || {
+    ///         std::thread::sleep(std::time::Duration::from_secs(1));
+    ///         token.cancel();
+    ///     });
+    ///
+    ///     Ok(task.promise(move |mut cx, count| match count {
+    ///         Some(count) => Ok(cx.number(count as f64)),
+    ///         None => cx.throw_error("timed out"),
+    ///     }))
+    /// }
+    /// ```
+    pub fn with_cancellation<O, F>(
+        cx: &'cx mut C,
+        execute: F,
+    ) -> (TaskBuilder<'cx, C, Cancellable<F>>, CancelToken)
+    where
+        O: Send + 'static,
+        F: FnOnce(&CancelToken) -> O + Send + 'static,
+    {
+        let token = CancelToken::new();
+        let execute = Cancellable {
+            execute,
+            token: token.clone(),
+        };
+
+        (TaskBuilder { cx, execute }, token)
+    }
+}
+
 impl<'a: 'cx, 'cx, C, O, E> TaskBuilder<'cx, C, E>
 where
     C: Context<'a>,
@@ -58,6 +132,30 @@ where
     /// The `complete` callback will execute on the JavaScript main thread and
     /// is passed the return value from `execute`. If the `complete` callback
     /// throws, the promise will be rejected with the exception
+    ///
+    /// If the `complete` callback returns a [`JsPromise`], the returned promise adopts
+    /// its state, following the same rules as resolving a JavaScript `Promise`. This
+    /// allows chaining multiple asynchronous stages, such as scheduling a second task
+    /// with the output of the first:
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn parse_and_square(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let input = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     let promise = cx
+    ///         .task(move || input.parse::<f64>().ok())
+    ///         .promise(move |mut cx, n| match n {
+    ///             // Resolved with the result of a second task
+    ///             Some(n) => Ok(cx
+    ///                 .task(move || n * n)
+    ///                 .promise(|mut cx, n| Ok(cx.number(n)))),
+    ///             None => cx.throw_type_error("expected a number"),
+    ///         });
+    ///
+    ///     Ok(promise)
+    /// }
+    /// ```
     pub fn promise<V, F>(self, complete: F) -> Handle<'a, JsPromise>
     where
         V: Value,
@@ -73,6 +171,80 @@ where
     }
 }
 
+impl<'a: 'cx, 'cx, C, O, F> TaskBuilder<'cx, C, Cancellable<F>>
+where
+    C: Context<'a>,
+    O: Send + 'static,
+    F: FnOnce(&CancelToken) -> O + Send + 'static,
+{
+    /// Schedules a cancellable task to execute on the Node worker pool and returns a
+    /// promise that is resolved with the value from the `complete` callback.
+    ///
+    /// If the task is cancelled before `execute` starts running, neither `execute`
+    /// nor `complete` is called and the promise is rejected with an `Error`. Otherwise,
+    /// this behaves like the `promise` method of tasks created with
+    /// [`TaskBuilder::new`].
+    pub fn promise<V, D>(self, complete: D) -> Handle<'a, JsPromise>
+    where
+        V: Value,
+        D: FnOnce(Cx, O) -> JsResult<V> + 'static,
+    {
+        let env = self.cx.env();
+        let (deferred, promise) = JsPromise::new(self.cx);
+        let Cancellable { execute, token } = self.execute;
+
+        // Skip `execute` if the task was cancelled before it started
+        let execute = move || (!token.is_cancelled()).then(|| execute(&token));
+
+        schedule_promise(
+            env,
+            execute,
+            move |mut cx, output| match output {
+                Some(output) => complete(cx, output),
+                None => cx.throw_error("task was cancelled"),
+            },
+            deferred,
+        );
+
+        promise
+    }
+}
+
+/// The `execute` callback of a task created with [`TaskBuilder::with_cancellation`].
+///
+/// Unlike other tasks, cancellable tasks do not provide
+/// [`and_then`](TaskBuilder::and_then), since its `complete` callback could not be told
+/// that a task was cancelled before it started.
+pub struct Cancellable<F> {
+    execute: F,
+    token: CancelToken,
+}
+
+/// A token used to request cancellation of a task created with
+/// [`TaskBuilder::with_cancellation`].
+///
+/// Tokens are cheap to clone and may be shared with other threads. All clones refer
+/// to the same task.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation of the task. Calling this more than once has no
+    /// additional effect.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Indicates whether cancellation of the task has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
 // Schedule a task to execute on the Node worker pool
 fn schedule<I, O, D>(env: Env, input: I, data: D)
 where
@@ -1,4 +1,8 @@
-use std::{future::Future, pin::Pin};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+};
 
 use crate::{context::Cx, thread::LocalKey};
 
@@ -7,9 +11,36 @@ pub(crate) mod tokio;
 
 type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
 
-pub(crate) static RUNTIME: LocalKey<Box<dyn Runtime>> = LocalKey::new();
+pub(crate) static RUNTIME: LocalKey<Mutex<Option<Arc<dyn Executor>>>> = LocalKey::new();
 
-pub trait Runtime: Send + Sync + 'static {
+/// An asynchronous runtime capable of running [`Future`]s spawned by Neon.
+///
+/// Neon uses the executor registered with [`set_global_executor`] to drive the
+/// futures of `async` functions exported with [`#[neon::export]`](crate::export).
+/// Implementations are provided for `tokio` runtimes and handles when the
+/// **`tokio-rt`** feature is enabled. Other runtimes, such as `async-std`, `smol`,
+/// or a custom executor, may be used by implementing this trait.
+///
+/// ```
+/// # fn main() {
+/// # #[cfg(feature = "tokio-rt")]
+/// # fn example() {
+/// use std::{future::Future, pin::Pin};
+///
+/// // Wraps a runtime in order to log each spawned future
+/// struct Logged(tokio::runtime::Handle);
+///
+/// impl neon::Executor for Logged {
+///     fn spawn(&self, fut: Pin<Box<dyn Future<Output = ()> + Send + 'static>>) {
+///         eprintln!("Spawning a future");
+///         self.0.spawn(fut);
+///     }
+/// }
+/// # }
+/// # }
+/// ```
+pub trait Executor: Send + Sync + 'static {
+    /// Spawns a future, running it to completion in the background
     fn spawn(&self, fut: BoxFuture);
 }
 
@@ -49,13 +80,43 @@ pub trait Runtime: Send + Sync + 'static {
 /// ```
 pub fn set_global_executor<R>(cx: &mut Cx, runtime: R) -> Result<(), R>
 where
-    R: Runtime,
+    R: Executor,
 {
-    if RUNTIME.get(cx).is_some() {
+    let mut current = runtime_cell(cx);
+
+    if current.is_some() {
         return Err(runtime);
     }
 
-    RUNTIME.get_or_init(cx, || Box::new(runtime));
+    *current = Some(Arc::new(runtime));
 
     Ok(())
 }
+
+/// Returns the [`Future`] executor runtime registered to the addon, if any.
+///
+/// See [`set_global_executor`] for registering a runtime.
+pub fn global_executor(cx: &mut Cx) -> Option<Arc<dyn Executor>> {
+    runtime_cell(cx).clone()
+}
+
+/// Register a [`Future`] executor runtime globally to the addon, replacing any
+/// previously registered runtime.
+///
+/// Returns the previous runtime, if one was set. Futures that were already spawned
+/// continue to run on the previous runtime.
+///
+/// Unlike [`set_global_executor`], this always installs `runtime`. It is useful for
+/// embedders that want to conditionally install their own runtime in place of a
+/// default one.
+pub fn replace_global_executor<R>(cx: &mut Cx, runtime: R) -> Option<Arc<dyn Executor>>
+where
+    R: Executor,
+{
+    runtime_cell(cx).replace(Arc::new(runtime))
+}
+
+fn runtime_cell<'cx>(cx: &mut Cx<'cx>) -> MutexGuard<'cx, Option<Arc<dyn Executor>>> {
+    // The lock is never held while calling user code, so it cannot be poisoned
+    RUNTIME.get_or_init_default(cx).lock().unwrap()
+}
@@ -1,32 +1,32 @@
 use std::sync::Arc;
 
-use super::{BoxFuture, Runtime};
+use super::{BoxFuture, Executor};
 
-impl Runtime for tokio::runtime::Runtime {
+impl Executor for tokio::runtime::Runtime {
     fn spawn(&self, fut: BoxFuture) {
         spawn(self.handle(), fut);
     }
 }
 
-impl Runtime for Arc<tokio::runtime::Runtime> {
+impl Executor for Arc<tokio::runtime::Runtime> {
     fn spawn(&self, fut: BoxFuture) {
         spawn(self.handle(), fut);
     }
 }
 
-impl Runtime for &'static tokio::runtime::Runtime {
+impl Executor for &'static tokio::runtime::Runtime {
     fn spawn(&self, fut: BoxFuture) {
         spawn(self.handle(), fut);
     }
 }
 
-impl Runtime for tokio::runtime::Handle {
+impl Executor for tokio::runtime::Handle {
     fn spawn(&self, fut: BoxFuture) {
         spawn(self, fut);
     }
 }
 
-impl Runtime for &'static tokio::runtime::Handle {
+impl Executor for &'static tokio::runtime::Handle {
     fn spawn(&self, fut: BoxFuture) {
         spawn(self, fut);
     }
@@ -46,21 +46,23 @@ pub(crate) fn init(cx: &mut crate::context::ModuleContext) -> crate::result::Neo
 
     static RUNTIME: OnceCell<Runtime> = OnceCell::new();
 
-    super::RUNTIME.get_or_try_init(cx, |cx| {
-        let runtime = RUNTIME
-            .get_or_try_init(|| {
-                #[cfg(feature = "tokio-rt-multi-thread")]
-                let mut builder = Builder::new_multi_thread();
+    if super::global_executor(cx).is_some() {
+        return Ok(());
+    }
+
+    let runtime = RUNTIME
+        .get_or_try_init(|| {
+            #[cfg(feature = "tokio-rt-multi-thread")]
+            let mut builder = Builder::new_multi_thread();
 
-                #[cfg(not(feature = "tokio-rt-multi-thread"))]
-                let mut builder = Builder::new_current_thread();
+            #[cfg(not(feature = "tokio-rt-multi-thread"))]
+            let mut builder = Builder::new_current_thread();
 
-                builder.enable_all().build()
-            })
-            .or_else(|err| cx.throw_error(err.to_string()))?;
+            builder.enable_all().build()
+        })
+        .or_else(|err| cx.throw_error(err.to_string()))?;
 
-        Ok(Box::new(runtime))
-    })?;
+    let _ = super::set_global_executor(cx, runtime);
 
     Ok(())
 }
@@ -0,0 +1,132 @@
+// JavaScript's loose equality (`==`), implemented with Node-API rather than by
+// evaluating `a == b`.
+//
+// The steps follow
+// [IsLooselyEqual](https://tc39.es/ecma262/#sec-islooselyequal). Conversions that
+// cannot run user code use Node-API coercions, while `ToPrimitive` is implemented
+// here because Node-API does not expose it. The original `BigInt` and `Symbol` globals
+// are cached with `global_function!`.
+
+use crate::{
+    context::{global_function, internal::ContextInternal, Context, Cx},
+    handle::Handle,
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, bindings::ValueType},
+    types::{private::ValueInternal, JsFunction, JsNumber, JsObject, JsValue},
+};
+
+global_function!(bigint_function, "BigInt");
+global_function!(symbol_function, "Symbol");
+
+pub(super) fn loose_equals<'cx>(
+    cx: &mut Cx<'cx>,
+    mut x: Handle<'cx, JsValue>,
+    mut y: Handle<'cx, JsValue>,
+) -> NeonResult<bool> {
+    loop {
+        match (value_type(cx, x), value_type(cx, y)) {
+            (tx, ty) if tx == ty => return Ok(x.strict_equals(cx, y)),
+            (ValueType::Null | ValueType::Undefined, ValueType::Null | ValueType::Undefined) => {
+                return Ok(true)
+            }
+            (ValueType::Number, ValueType::String) => y = cx.coerce_to_number(y)?.upcast(),
+            (ValueType::String, ValueType::Number) => x = cx.coerce_to_number(x)?.upcast(),
+            (ValueType::BigInt, ValueType::String) => match string_to_bigint(cx, y)? {
+                Some(n) => y = n,
+                None => return Ok(false),
+            },
+            (ValueType::String, ValueType::BigInt) => match string_to_bigint(cx, x)? {
+                Some(n) => x = n,
+                None => return Ok(false),
+            },
+            (ValueType::Boolean, _) => x = cx.coerce_to_number(x)?.upcast(),
+            (_, ValueType::Boolean) => y = cx.coerce_to_number(y)?.upcast(),
+            (
+                ValueType::String | ValueType::Number | ValueType::BigInt | ValueType::Symbol,
+                ValueType::Object,
+            ) => y = to_primitive(cx, y)?,
+            (
+                ValueType::Object,
+                ValueType::String | ValueType::Number | ValueType::BigInt | ValueType::Symbol,
+            ) => x = to_primitive(cx, x)?,
+            (ValueType::BigInt, ValueType::Number) => return bigint_equals_number(cx, x, y),
+            (ValueType::Number, ValueType::BigInt) => return bigint_equals_number(cx, y, x),
+            _ => return Ok(false),
+        }
+    }
+}
+
+// The type of `value`, treating functions and externals as objects
+fn value_type(cx: &mut Cx, value: Handle<JsValue>) -> ValueType {
+    match unsafe { sys::tag::value_type(cx.env().to_raw(), value.to_local()) } {
+        ValueType::Function | ValueType::External => ValueType::Object,
+        ty => ty,
+    }
+}
+
+// Converts a string to a `BigInt` like `BigInt(s)`, returning `None` instead of
+// throwing a `SyntaxError` if the string is not an integer
+fn string_to_bigint<'cx>(
+    cx: &mut Cx<'cx>,
+    s: Handle<'cx, JsValue>,
+) -> NeonResult<Option<Handle<'cx, JsValue>>> {
+    let bigint = bigint_function(cx)?;
+
+    Ok(cx.try_catch(|cx| bigint.bind(cx).arg(s)?.call()).ok())
+}
+
+fn bigint_equals_number<'cx>(
+    cx: &mut Cx<'cx>,
+    x: Handle<'cx, JsValue>,
+    y: Handle<'cx, JsValue>,
+) -> NeonResult<bool> {
+    let n = y.downcast_or_throw::<JsNumber, _>(cx)?.value(cx);
+
+    // Only integers can equal a `BigInt`, and converting them to one is exact
+    if !n.is_finite() || n.fract() != 0.0 {
+        return Ok(false);
+    }
+
+    let y: Handle<JsValue> = bigint_function(cx)?.bind(cx).arg(n)?.call()?;
+
+    Ok(x.strict_equals(cx, y))
+}
+
+// Converts an object to a primitive with
+// [ToPrimitive](https://tc39.es/ecma262/#sec-toprimitive) and no preferred type
+fn to_primitive<'cx>(cx: &mut Cx<'cx>, value: Handle<'cx, JsValue>) -> JsResult<'cx, JsValue> {
+    // Property access and calls behave the same for functions and externals as for
+    // any other object
+    let object = Handle::new_internal(unsafe { JsObject::from_local(cx.env(), value.to_local()) });
+    let symbol = symbol_function(cx)?.get_value(cx, "toPrimitive")?;
+    let exotic = object.get_value(cx, symbol)?;
+
+    if !matches!(
+        value_type(cx, exotic),
+        ValueType::Undefined | ValueType::Null
+    ) {
+        let exotic: Handle<JsFunction> = exotic.downcast_or_throw(cx)?;
+        let result: Handle<JsValue> = exotic.bind(cx).this(object)?.arg("default")?.call()?;
+
+        if value_type(cx, result) == ValueType::Object {
+            return cx.throw_type_error("Cannot convert object to primitive value");
+        }
+
+        return Ok(result);
+    }
+
+    for name in ["valueOf", "toString"] {
+        let method = object.get_value(cx, name)?;
+
+        if let Ok(method) = method.downcast::<JsFunction, _>(cx) {
+            let result: Handle<JsValue> = method.bind(cx).this(object)?.call()?;
+
+            if value_type(cx, result) != ValueType::Object {
+                return Ok(result);
+            }
+        }
+    }
+
+    cx.throw_type_error("Cannot convert object to primitive value")
+}
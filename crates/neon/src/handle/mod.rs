@@ -42,6 +42,7 @@
 //! }
 //! ```
 
+mod equality;
 pub(crate) mod internal;
 
 pub(crate) mod root;
@@ -59,9 +60,9 @@ pub use self::root::Root;
 use crate::{
     context::Context,
     handle::internal::{SuperType, TransparentNoCopyWrapper},
-    result::{JsResult, ResultExt},
+    result::{JsResult, NeonResult, ResultExt},
     sys,
-    types::Value,
+    types::{JsNull, Value},
 };
 
 /// A handle to a JavaScript value that is owned by the JavaScript engine.
@@ -171,12 +172,28 @@ impl<'a, T: Value> Handle<'a, T> {
     }
 
     /// Attempts to downcast a handle to another type, raising a JavaScript `TypeError`
-    /// exception on failure. This method is a convenient shorthand, equivalent to
-    /// `self.downcast::<U>().or_throw::<C>(cx)`.
+    /// exception on failure. This method is a convenient shorthand, similar to
+    /// `self.downcast::<U>().or_throw::<C>(cx)`, except that the error message also
+    /// includes the actual type of the value, e.g.
+    /// `"failed to downcast any to number (found string)"`.
     pub fn downcast_or_throw<'b, U: Value, C: Context<'b>>(&self, cx: &mut C) -> JsResult<'a, U> {
-        self.downcast(cx).or_throw(cx)
+        match self.downcast(cx) {
+            Ok(v) => Ok(v),
+            Err(err) => {
+                let found = if self.is_a::<JsNull, _>(cx) {
+                    "null"
+                } else {
+                    unsafe { sys::tag::type_of(cx.env().to_raw(), self.to_local()) }
+                };
+
+                cx.throw_type_error(format!("{err} (found {found})"))
+            }
+        }
     }
 
+    /// Compares this value to `other` with JavaScript's
+    /// [strict equality](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Strict_equality)
+    /// (`===`) semantics.
     pub fn strict_equals<'b, U: Value, C: Context<'b>>(
         &self,
         cx: &mut C,
@@ -184,6 +201,24 @@ impl<'a, T: Value> Handle<'a, T> {
     ) -> bool {
         unsafe { sys::mem::strict_equals(cx.env().to_raw(), self.to_local(), other.to_local()) }
     }
+
+    /// Compares this value to `other` with JavaScript's
+    /// [loose equality](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Equality)
+    /// (`==`) semantics.
+    ///
+    /// Loose equality may convert objects to primitives by calling their
+    /// [`Symbol.toPrimitive`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/toPrimitive),
+    /// `valueOf` or `toString` methods, so this may throw.
+    pub fn loose_equals<'b, U: Value, C: Context<'b>>(
+        &self,
+        cx: &mut C,
+        other: Handle<'b, U>,
+    ) -> NeonResult<bool> {
+        let cx = cx.cx_mut();
+        let this = self.as_value(cx);
+
+        equality::loose_equals(cx, this, other.upcast())
+    }
 }
 
 impl<'a, V: Value> Deref for Handle<'a, V> {
@@ -4,7 +4,8 @@ use crate::{
     context::Context,
     handle::Handle,
     object::Object,
-    sys::{raw, reference},
+    result::{NeonResult, Throw},
+    sys::{self, raw, reference},
     types::boxed::Finalize,
 };
 
@@ -96,6 +97,26 @@ impl<T: Object> Root<T> {
         let env = cx.env().to_raw();
         let internal = unsafe { reference::new(env, value.to_local()) };
 
+        Self::from_raw(cx, internal)
+    }
+
+    /// Fallible version of [`Root::new`].
+    ///
+    /// Instead of panicking, throws a JavaScript exception if Node-API fails to create
+    /// the reference, for example because the engine is out of memory.
+    pub fn try_new<'a, C: Context<'a>>(cx: &mut C, value: &T) -> NeonResult<Self> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            match reference::try_new(env, value.to_local()) {
+                Ok(internal) => Ok(Self::from_raw(cx, internal)),
+                Err(_) if sys::error::is_throwing(env) => Err(Throw::new()),
+                Err(status) => cx.throw_error(format!("failed to create reference: {status:?}")),
+            }
+        }
+    }
+
+    fn from_raw<'a, C: Context<'a>>(cx: &mut C, internal: sys::bindings::Ref) -> Self {
         Self {
             internal: Some(NapiRef(internal as *mut _)),
             instance_id: instance_id(cx),
@@ -117,6 +138,11 @@ impl<T: Object> Root<T> {
     /// # Ok(cx.undefined())
     /// # }
     /// ```
+    ///
+    /// Cloning increments the reference count of the underlying reference; the
+    /// returned `Root<T>` is independent and must be disposed of separately with
+    /// `Root::into_inner` or `Root::drop`, the same as a `Root<T>` created with
+    /// [`Root::new`].
     pub fn clone<'a, C: Context<'a>>(&self, cx: &mut C) -> Self {
         let env = cx.env();
         let internal = self.as_napi_ref(cx).0 as *mut _;
@@ -162,6 +188,21 @@ impl<T: Object> Root<T> {
         Handle::new_internal(unsafe { T::from_local(env, local) })
     }
 
+    /// Return the referenced JavaScript object, or the `Root` itself if it was created
+    /// by a different instance of the module.
+    ///
+    /// Unlike [`Root::into_inner`], this does not panic when called from the wrong
+    /// JavaScript thread. The `Root` is returned intact so that it may be stored or
+    /// disposed of later. This is useful for long-lived stores of roots that may be
+    /// accessed while a module instance, such as a worker thread, is shutting down.
+    pub fn try_into_inner<'a, C: Context<'a>>(self, cx: &mut C) -> Result<Handle<'a, T>, Self> {
+        if self.instance_id != instance_id(cx) {
+            return Err(self);
+        }
+
+        Ok(self.into_inner(cx))
+    }
+
     /// Access the inner JavaScript object without consuming the `Root`
     /// This method aliases the reference without changing the reference count. It
     /// can be used in place of a clone immediately followed by a call to `into_inner`.
@@ -104,7 +104,7 @@ pub mod sys;
 
 #[cfg(all(feature = "napi-6", feature = "futures"))]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-6", feature = "futures"))))]
-pub use executor::set_global_executor;
+pub use executor::{global_executor, replace_global_executor, set_global_executor, Executor};
 pub use types_docs::exports as types;
 
 #[doc(hidden)]
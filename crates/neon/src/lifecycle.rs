@@ -65,6 +65,9 @@ pub(crate) struct InstanceData {
 
     /// Table of user-defined instance-local cells.
     locals: LocalTable,
+
+    /// Value set with `cx.set_instance_data()`
+    user_data: Option<Box<dyn Any + Send + 'static>>,
 }
 
 #[derive(Default)]
@@ -265,6 +268,7 @@ impl InstanceData {
             drop_queue: Arc::new(drop_queue),
             shared_channel,
             locals: LocalTable::default(),
+            user_data: None,
         };
 
         unsafe { &mut *lifecycle::set_instance_data(env, data) }
@@ -294,4 +298,11 @@ impl InstanceData {
     pub(crate) fn locals<'cx, C: Context<'cx>>(cx: &mut C) -> &mut LocalTable {
         &mut InstanceData::get(cx).locals
     }
+
+    /// Helper to return a reference to the `user_data` field of `InstanceData`.
+    pub(crate) fn user_data<'cx, C: Context<'cx>>(
+        cx: &mut C,
+    ) -> &mut Option<Box<dyn Any + Send + 'static>> {
+        &mut InstanceData::get(cx).user_data
+    }
 }
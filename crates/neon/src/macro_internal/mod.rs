@@ -7,7 +7,7 @@ pub use linkme;
 use crate::{
     context::{Context, Cx, ModuleContext},
     handle::Handle,
-    result::{JsResult, NeonResult},
+    result::{IntoThrow, JsResult, NeonResult},
     types::{extract::TryIntoJs, JsValue},
 };
 
@@ -111,3 +111,63 @@ impl<Tag> NeonMarker<Tag, NeonResultTag> {
         }
     }
 }
+
+// Wrapper used to select how the error of a returned `Result` is thrown.
+// Implementations are provided at decreasing levels of reference so that, with
+// autoderef, `TryIntoJs` errors take precedence over `IntoThrow` errors and
+// non-`Result` values are passed through unchanged.
+pub struct NeonThrowWrap<'a, T>(pub &'a T);
+
+// Errors are converted with `TryIntoJs`
+pub struct NeonThrowTryIntoJsTag;
+
+// Errors are converted with `IntoThrow`
+pub struct NeonThrowIntoThrowTag;
+
+// Not a `Result`
+pub struct NeonThrowValueTag;
+
+pub trait NeonThrowViaTryIntoJs {
+    fn neon_throw_tag(&self) -> NeonThrowTryIntoJsTag {
+        NeonThrowTryIntoJsTag
+    }
+}
+
+impl<T, E> NeonThrowViaTryIntoJs for &&NeonThrowWrap<'_, Result<T, E>> where
+    E: for<'cx> TryIntoJs<'cx>
+{
+}
+
+pub trait NeonThrowViaIntoThrow {
+    fn neon_throw_tag(&self) -> NeonThrowIntoThrowTag {
+        NeonThrowIntoThrowTag
+    }
+}
+
+impl<T, E: IntoThrow> NeonThrowViaIntoThrow for &NeonThrowWrap<'_, Result<T, E>> {}
+
+pub trait NeonThrowViaValue {
+    fn neon_throw_tag(&self) -> NeonThrowValueTag {
+        NeonThrowValueTag
+    }
+}
+
+impl<T> NeonThrowViaValue for NeonThrowWrap<'_, T> {}
+
+impl NeonThrowTryIntoJsTag {
+    pub fn neon_throw<T>(self, _cx: &mut Cx, v: T) -> T {
+        v
+    }
+}
+
+impl NeonThrowIntoThrowTag {
+    pub fn neon_throw<T, E: IntoThrow>(self, cx: &mut Cx, res: Result<T, E>) -> NeonResult<T> {
+        res.or_else(|err| err.throw(cx))
+    }
+}
+
+impl NeonThrowValueTag {
+    pub fn neon_throw<T>(self, _cx: &mut Cx, v: T) -> T {
+        v
+    }
+}
@@ -8,8 +8,7 @@
 //! ## Property Keys
 //!
 //! Object properties are accessed by a _property key_, which in JavaScript
-//! can be a string or [symbol][symbol]. (Neon does not yet have support for
-//! symbols.) For convenience, the [`PropertyKey`] trait allows
+//! can be a string or [symbol][symbol]. For convenience, the [`PropertyKey`] trait allows
 //! Neon programs to use various Rust string types, as well as numeric types,
 //! as keys when accessing object properties, converting the keys to strings
 //! as necessary:
@@ -28,28 +27,46 @@
 //! }
 //! ```
 //!
+//! A handle to any JavaScript value can also be used as a key. This is how
+//! properties keyed by a symbol, such as the well-known
+//! [`Symbol.iterator`][iterator], are accessed:
+//!
+//! ```
+//! # use neon::prelude::*;
+//! fn get_iterator<'cx>(
+//!     cx: &mut Cx<'cx>,
+//!     obj: Handle<'cx, JsObject>
+//! ) -> JsResult<'cx, JsFunction> {
+//!     let symbol: Handle<JsFunction> = cx.global("Symbol")?;
+//!     let iterator: Handle<JsValue> = symbol.prop(cx, "iterator").get()?;
+//!
+//!     obj.prop(cx, iterator).get()
+//! }
+//! ```
+//!
 //! [hierarchy]: crate::types#the-javascript-type-hierarchy
 //! [symbol]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol
+//! [iterator]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/iterator
 
 use smallvec::smallvec;
 
 use crate::{
-    context::{internal::ContextInternal, Context, Cx},
+    context::{global_function, internal::ContextInternal, Context, Cx},
     handle::{Handle, Root},
-    result::{NeonResult, Throw},
+    result::{JsResult, NeonResult, Throw},
     sys::{self, raw},
     types::{
         build,
         extract::{TryFromJs, TryIntoJs},
-        function::{BindOptions, CallOptions},
+        function::{BindOptions, CallOptions, TryIntoArguments},
         private::ValueInternal,
         utf8::Utf8,
-        JsFunction, JsUndefined, JsValue, Value,
+        JsFunction, JsObject, JsUndefined, JsValue, Value,
     },
 };
 
 #[cfg(feature = "napi-6")]
-use crate::{result::JsResult, types::JsArray};
+use crate::types::JsArray;
 
 /// A property key in a JavaScript object.
 pub trait PropertyKey: Copy {
@@ -67,6 +84,20 @@ pub trait PropertyKey: Copy {
         obj: raw::Local,
         val: raw::Local,
     ) -> bool;
+
+    /// Mutates `out` to refer to this key as a JavaScript property key value, returning
+    /// `false` with an exception pending if it cannot be converted.
+    ///
+    /// Used by [`Object::delete`] and [`Object::define_property`]. The default
+    /// implementation throws a `TypeError`.
+    ///
+    /// # Safety
+    ///
+    /// `out` must only be read if this method returns `true`.
+    unsafe fn to_key<'c, C: Context<'c>>(self, cx: &mut C, _out: &mut raw::Local) -> bool {
+        let _ = cx.throw_type_error::<_, ()>("unsupported property key");
+        false
+    }
 }
 
 impl PropertyKey for u32 {
@@ -88,6 +119,15 @@ impl PropertyKey for u32 {
     ) -> bool {
         sys::object::set_index(out, cx.env().to_raw(), obj, self, val)
     }
+
+    unsafe fn to_key<'c, C: Context<'c>>(self, cx: &mut C, out: &mut raw::Local) -> bool {
+        let env = cx.env().to_raw();
+        let mut index: raw::Local = std::mem::zeroed();
+
+        // Some Node-API functions, e.g. `napi_has_own_property`, require a name
+        sys::primitive::number(&mut index, env, self.into());
+        sys::convert::to_string(out, env, index)
+    }
 }
 
 impl<'a, K: Value> PropertyKey for Handle<'a, K> {
@@ -113,6 +153,11 @@ impl<'a, K: Value> PropertyKey for Handle<'a, K> {
 
         sys::object::set(out, env, obj, self.to_local(), val)
     }
+
+    unsafe fn to_key<'c, C: Context<'c>>(self, _cx: &mut C, out: &mut raw::Local) -> bool {
+        *out = self.to_local();
+        true
+    }
 }
 
 impl<'a> PropertyKey for &'a str {
@@ -140,6 +185,13 @@ impl<'a> PropertyKey for &'a str {
 
         sys::object::set_string(env, out, obj, ptr, len, val)
     }
+
+    unsafe fn to_key<'c, C: Context<'c>>(self, cx: &mut C, out: &mut raw::Local) -> bool {
+        let (ptr, len) = Utf8::from(self).into_small_unwrap().lower();
+        let env = cx.env().to_raw();
+
+        sys::string::new(out, env, ptr, len)
+    }
 }
 
 /// A builder for accessing an object property.
@@ -215,6 +267,48 @@ where
         R::from_js(self.cx, v)
     }
 
+    /// Gets the property from the object and attempts to convert it to a Rust value,
+    /// returning `default` if the property is `undefined` or `null`.
+    ///
+    /// This is a shorthand for `get::<Option<R>>()` followed by
+    /// [`Option::unwrap_or`]. As with [`get`](PropOptions::get), a missing property is
+    /// read as `undefined`, and any other value that cannot be converted throws.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn foo(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    /// let options: Handle<JsObject> = cx.argument(0)?;
+    /// let retries: f64 = options.prop(&mut cx, "retries").get_or(3.0)?;
+    /// # Ok(cx.number(retries))
+    /// # }
+    /// ```
+    pub fn get_or<R: TryFromJs<'cx>>(&mut self, default: R) -> NeonResult<R> {
+        Ok(self.get::<Option<R>>()?.unwrap_or(default))
+    }
+
+    /// Gets the property from the object and attempts to convert it to a Rust value,
+    /// computing a fallback with `f` if the property is `undefined` or `null`.
+    ///
+    /// See [`get_or`](PropOptions::get_or).
+    pub fn get_or_else<R, F>(&mut self, f: F) -> NeonResult<R>
+    where
+        R: TryFromJs<'cx>,
+        F: FnOnce(&mut Cx<'cx>) -> R,
+    {
+        match self.get::<Option<R>>()? {
+            Some(v) => Ok(v),
+            None => Ok(f(self.cx)),
+        }
+    }
+
+    /// Gets the property from the object and attempts to convert it to a Rust value,
+    /// returning [`R::default()`](Default::default) if the property is `undefined` or `null`.
+    ///
+    /// See [`get_or`](PropOptions::get_or).
+    pub fn get_or_default<R: TryFromJs<'cx> + Default>(&mut self) -> NeonResult<R> {
+        Ok(self.get::<Option<R>>()?.unwrap_or_default())
+    }
+
     /// Sets the property on the object to a value converted from Rust.
     ///
     /// May throw an exception either during converting the value or setting the property.
@@ -254,6 +348,207 @@ where
     }
 }
 
+/// Selects the keys returned by [`Object::own_property_keys`].
+///
+/// By default, all own property keys are selected, including non-enumerable properties
+/// and symbols. Each method narrows the selection further.
+///
+/// ```
+/// # #[cfg(feature = "napi-6")]
+/// # {
+/// use neon::object::PropertyFilter;
+///
+/// // Enumerable, writable properties with string keys
+/// let filter = PropertyFilter::new().enumerable().writable().skip_symbols();
+/// # }
+/// ```
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+#[derive(Clone, Copy, Debug)]
+pub struct PropertyFilter(sys::KeyFilter);
+
+#[cfg(feature = "napi-6")]
+impl PropertyFilter {
+    /// Selects all own property keys.
+    pub fn new() -> Self {
+        Self(sys::KeyFilter::ALL_PROPERTIES)
+    }
+
+    /// Only selects enumerable properties.
+    pub fn enumerable(self) -> Self {
+        Self(self.0 | sys::KeyFilter::ENUMERABLE)
+    }
+
+    /// Only selects writable properties.
+    pub fn writable(self) -> Self {
+        Self(self.0 | sys::KeyFilter::WRITABLE)
+    }
+
+    /// Only selects configurable properties.
+    pub fn configurable(self) -> Self {
+        Self(self.0 | sys::KeyFilter::CONFIGURABLE)
+    }
+
+    /// Excludes properties with string keys.
+    pub fn skip_strings(self) -> Self {
+        Self(self.0 | sys::KeyFilter::SKIP_STRINGS)
+    }
+
+    /// Excludes properties with symbol keys.
+    pub fn skip_symbols(self) -> Self {
+        Self(self.0 | sys::KeyFilter::SKIP_SYMBOLS)
+    }
+}
+
+#[cfg(feature = "napi-6")]
+impl Default for PropertyFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Describes a property to be created or modified by [`Object::define_property`].
+///
+/// A descriptor is either a _data descriptor_, with a [`value`](PropertyDescriptor::value)
+/// that may be [`writable`](PropertyDescriptor::writable), or an _accessor descriptor_,
+/// with a [`get`](PropertyDescriptor::get) and/or [`set`](PropertyDescriptor::set)
+/// function. As with
+/// [`Object.defineProperty`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty),
+/// only the fields that are set are changed when modifying an existing property, and
+/// attributes that are not set default to `false` when creating a new property.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::object::PropertyDescriptor;
+///
+/// fn with_hidden_id(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let obj = cx.empty_object();
+///     let id = cx.number(42);
+///
+///     // A read-only property that is skipped by `Object.keys` and `JSON.stringify`
+///     obj.define_property(&mut cx, "id", PropertyDescriptor::new().value(id))?;
+///
+///     Ok(obj)
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PropertyDescriptor<'a> {
+    value: Option<Handle<'a, JsValue>>,
+    get: Option<Handle<'a, JsFunction>>,
+    set: Option<Handle<'a, JsFunction>>,
+    writable: Option<bool>,
+    enumerable: Option<bool>,
+    configurable: Option<bool>,
+}
+
+impl<'a> PropertyDescriptor<'a> {
+    /// Creates an empty descriptor, with no fields set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the value of the property.
+    pub fn value<V: Value>(mut self, value: Handle<'a, V>) -> Self {
+        self.value = Some(value.upcast());
+        self
+    }
+
+    /// Sets the function called to get the value of the property.
+    pub fn get(mut self, get: Handle<'a, JsFunction>) -> Self {
+        self.get = Some(get);
+        self
+    }
+
+    /// Sets the function called with the new value when the property is assigned.
+    pub fn set(mut self, set: Handle<'a, JsFunction>) -> Self {
+        self.set = Some(set);
+        self
+    }
+
+    /// Sets whether the value of the property can be changed by assignment.
+    pub fn writable(mut self, writable: bool) -> Self {
+        self.writable = Some(writable);
+        self
+    }
+
+    /// Sets whether the property is listed by enumerations such as `Object.keys`.
+    pub fn enumerable(mut self, enumerable: bool) -> Self {
+        self.enumerable = Some(enumerable);
+        self
+    }
+
+    /// Sets whether the property can be deleted or redefined.
+    pub fn configurable(mut self, configurable: bool) -> Self {
+        self.configurable = Some(configurable);
+        self
+    }
+
+    fn is_accessor(&self) -> bool {
+        self.get.is_some() || self.set.is_some()
+    }
+
+    // The value and attributes of a data descriptor that sets every field. Node-API
+    // always defines all attributes of a property, so it can only be used when none
+    // of them would be left unchanged.
+    fn complete_data(&self) -> Option<(Handle<'a, JsValue>, sys::PropertyAttributes)> {
+        if self.is_accessor() {
+            return None;
+        }
+
+        let value = self.value?;
+        let mut attributes = sys::PropertyAttributes::DEFAULT;
+
+        if self.writable? {
+            attributes |= sys::PropertyAttributes::WRITABLE;
+        }
+
+        if self.enumerable? {
+            attributes |= sys::PropertyAttributes::ENUMERABLE;
+        }
+
+        if self.configurable? {
+            attributes |= sys::PropertyAttributes::CONFIGURABLE;
+        }
+
+        Some((value, attributes))
+    }
+
+    // Converts to a descriptor object with only the fields that are set
+    fn to_object(self, cx: &mut Cx<'a>) -> JsResult<'a, JsObject> {
+        let desc = cx.empty_object();
+
+        if let Some(value) = self.value {
+            desc.prop(cx, "value").set(value)?;
+        }
+
+        if let Some(get) = self.get {
+            desc.prop(cx, "get").set(get)?;
+        }
+
+        if let Some(set) = self.set {
+            desc.prop(cx, "set").set(set)?;
+        }
+
+        if let Some(writable) = self.writable {
+            desc.prop(cx, "writable").set(writable)?;
+        }
+
+        if let Some(enumerable) = self.enumerable {
+            desc.prop(cx, "enumerable").set(enumerable)?;
+        }
+
+        if let Some(configurable) = self.configurable {
+            desc.prop(cx, "configurable").set(configurable)?;
+        }
+
+        Ok(desc)
+    }
+}
+
+global_function!(object_define_property, |cx| cx
+    .global::<JsFunction>("Object")?
+    .get(cx, "defineProperty"));
+
 /// The trait of all object types.
 pub trait Object: Value {
     /// Create a [`PropOptions`] for accessing a property.
@@ -295,6 +590,28 @@ pub trait Object: Value {
         })
     }
 
+    /// Calls a method of the object with `this` bound to the object.
+    ///
+    /// This is a shorthand for `obj.method(cx, key)?.args(args)?.call()`, where `args`
+    /// is a tuple of values or a slice of handles.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn foo(mut cx: FunctionContext) -> JsResult<JsValue> {
+    /// let words: Handle<JsArray> = cx.argument(0)?;
+    ///
+    /// words.call_method(&mut cx, "join", (", ",))
+    /// # }
+    /// ```
+    fn call_method<'a, C, K, A>(&self, cx: &mut C, key: K, args: A) -> JsResult<'a, JsValue>
+    where
+        C: Context<'a>,
+        K: PropertyKey,
+        A: TryIntoArguments<'a>,
+    {
+        self.method(cx.cx_mut(), key)?.args(args)?.call()
+    }
+
     #[deprecated(since = "TBD", note = "use `Object::prop()` instead")]
     fn get_opt<'a, V: Value, C: Context<'a>, K: PropertyKey>(
         &self,
@@ -340,7 +657,56 @@ pub trait Object: Value {
         })
     }
 
+    /// Returns the own property keys of the object that match `filter`, as an array of
+    /// strings and symbols.
+    ///
+    /// Unlike [`get_own_property_names`](Object::get_own_property_names), this can include
+    /// non-enumerable properties and symbol keys. Integer keys are converted to strings.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::object::PropertyFilter;
+    ///
+    /// fn symbols(mut cx: FunctionContext) -> JsResult<JsArray> {
+    ///     let obj: Handle<JsObject> = cx.argument(0)?;
+    ///
+    ///     obj.own_property_keys(&mut cx, PropertyFilter::new().skip_strings())
+    /// }
+    /// ```
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    fn own_property_keys<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        filter: PropertyFilter,
+    ) -> JsResult<'a, JsArray> {
+        let env = cx.env();
+
+        build(cx.env(), |out| unsafe {
+            sys::object::get_own_property_keys(out, env.to_raw(), self.to_local(), filter.0)
+        })
+    }
+
+    /// Freezes the object, equivalent to the JavaScript expression
+    /// [`Object.freeze(this)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze).
+    ///
+    /// A frozen object can no longer be changed: properties cannot be added, removed,
+    /// or reassigned. Throws a `TypeError` if the object cannot be frozen, such as a
+    /// typed array with elements.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn config(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let config = cx.empty_object();
+    ///
+    ///     config.prop(&mut cx, "retries").set(3)?;
+    ///     config.freeze(&mut cx)?;
+    ///
+    ///     Ok(config)
+    /// }
+    /// ```
     #[cfg(feature = "napi-8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
     fn freeze<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<&Self> {
         let env = cx.env().to_raw();
         let obj = self.to_local();
@@ -353,7 +719,14 @@ pub trait Object: Value {
         }
     }
 
+    /// Seals the object, equivalent to the JavaScript expression
+    /// [`Object.seal(this)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/seal).
+    ///
+    /// Properties cannot be added to or removed from a sealed object, but existing
+    /// writable properties can still be reassigned. Throws a `TypeError` if the object
+    /// cannot be sealed.
     #[cfg(feature = "napi-8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
     fn seal<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<&Self> {
         let env = cx.env().to_raw();
         let obj = self.to_local();
@@ -366,6 +739,166 @@ pub trait Object: Value {
         }
     }
 
+    /// Checks whether the object or its prototype chain has a property, equivalent to
+    /// the JavaScript expression
+    /// [`key in this`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/in).
+    ///
+    /// Unlike getting the property and comparing it to `undefined`, this returns `true`
+    /// for properties whose value is `undefined`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn foo(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    /// let obj: Handle<JsObject> = cx.argument(0)?;
+    /// let has_name = obj.has(&mut cx, "name")?;
+    /// # Ok(cx.boolean(has_name))
+    /// # }
+    /// ```
+    fn has<'a, C: Context<'a>, K: PropertyKey>(&self, cx: &mut C, key: K) -> NeonResult<bool> {
+        let mut result = false;
+
+        unsafe {
+            let mut key_val: raw::Local = std::mem::zeroed();
+
+            if key.to_key(cx, &mut key_val)
+                && sys::object::has(&mut result, cx.env().to_raw(), self.to_local(), key_val)
+            {
+                Ok(result)
+            } else {
+                Err(Throw::new())
+            }
+        }
+    }
+
+    /// Checks whether the object has an own property, i.e., one that is not inherited
+    /// from its prototype chain. Equivalent to the JavaScript expression
+    /// [`Object.hasOwn(this, key)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/hasOwn).
+    fn has_own<'a, C: Context<'a>, K: PropertyKey>(&self, cx: &mut C, key: K) -> NeonResult<bool> {
+        let mut result = false;
+
+        unsafe {
+            let mut key_val: raw::Local = std::mem::zeroed();
+
+            if key.to_key(cx, &mut key_val)
+                && sys::object::has_own(&mut result, cx.env().to_raw(), self.to_local(), key_val)
+            {
+                Ok(result)
+            } else {
+                Err(Throw::new())
+            }
+        }
+    }
+
+    /// Deletes a property from the object, equivalent to the JavaScript expression
+    /// [`delete this[key]`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/delete).
+    ///
+    /// Returns `false` if the property could not be deleted, for example because the
+    /// property is non-configurable or the object is frozen. May throw an exception,
+    /// for example from a [`Proxy`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy)
+    /// trap.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn foo(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    /// let obj: Handle<JsObject> = cx.argument(0)?;
+    /// let deleted = obj.delete(&mut cx, "name")?;
+    /// # Ok(cx.boolean(deleted))
+    /// # }
+    /// ```
+    fn delete<'a, C: Context<'a>, K: PropertyKey>(&self, cx: &mut C, key: K) -> NeonResult<bool> {
+        let mut result = false;
+
+        unsafe {
+            let mut key_val: raw::Local = std::mem::zeroed();
+
+            if key.to_key(cx, &mut key_val)
+                && sys::object::delete(&mut result, cx.env().to_raw(), self.to_local(), key_val)
+            {
+                Ok(result)
+            } else {
+                Err(Throw::new())
+            }
+        }
+    }
+
+    /// Defines a new property or modifies an existing property of the object, equivalent
+    /// to the JavaScript expression
+    /// [`Object.defineProperty(this, key, descriptor)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty).
+    ///
+    /// Throws a `TypeError` if the property cannot be defined, for example because an
+    /// existing property is non-configurable, or if the descriptor has accessor
+    /// functions and is also given a value or made writable.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::object::PropertyDescriptor;
+    ///
+    /// fn define_full_name(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let person: Handle<JsObject> = cx.argument(0)?;
+    ///     let get = JsFunction::new(&mut cx, |mut cx| {
+    ///         let this: Handle<JsObject> = cx.this()?;
+    ///         let first: String = this.prop(&mut cx, "first").get()?;
+    ///         let last: String = this.prop(&mut cx, "last").get()?;
+    ///
+    ///         Ok(cx.string(format!("{first} {last}")))
+    ///     })?;
+    ///
+    ///     let descriptor = PropertyDescriptor::new().get(get).enumerable(true);
+    ///
+    ///     person.define_property(&mut cx, "fullName", descriptor)?;
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    fn define_property<'a, C: Context<'a>, K: PropertyKey>(
+        &self,
+        cx: &mut C,
+        key: K,
+        descriptor: PropertyDescriptor<'a>,
+    ) -> NeonResult<()> {
+        let mut key_val: raw::Local = unsafe { std::mem::zeroed() };
+
+        if !unsafe { key.to_key(cx, &mut key_val) } {
+            return Err(unsafe { Throw::new() });
+        }
+
+        if descriptor.is_accessor() && (descriptor.value.is_some() || descriptor.writable.is_some())
+        {
+            return cx.throw_type_error("accessor property cannot have a value or be writable");
+        }
+
+        if let Some((value, attributes)) = descriptor.complete_data() {
+            let env = cx.env().to_raw();
+
+            return unsafe {
+                match sys::object::define_value(
+                    env,
+                    self.to_local(),
+                    key_val,
+                    value.to_local(),
+                    attributes,
+                ) {
+                    Ok(()) => Ok(()),
+                    Err(_) if sys::error::is_throwing(env) => Err(Throw::new()),
+                    Err(_) => cx.throw_type_error("cannot define property"),
+                }
+            };
+        }
+
+        // Partial descriptors must leave the other fields of an existing property
+        // unchanged, and Node-API can only define accessors backed by native callbacks,
+        // so these are defined with the original `Object.defineProperty`
+        let cx = cx.cx_mut();
+        let desc = descriptor.to_object(cx)?;
+
+        object_define_property(cx)?
+            .bind(cx)
+            .arg(JsValue::new_internal(self.to_local()))?
+            .arg(JsValue::new_internal(key_val))?
+            .arg(desc)?
+            .exec()
+    }
+
     #[deprecated(since = "TBD", note = "use `Object::prop()` instead")]
     fn set<'a, C: Context<'a>, K: PropertyKey, W: Value>(
         &self,
@@ -387,6 +920,14 @@ pub trait Object: Value {
         Root::new(cx, self)
     }
 
+    /// Fallible version of [`Object::root`] that throws a JavaScript exception instead
+    /// of panicking if the reference cannot be created.
+    ///
+    /// See [`Root::try_new`].
+    fn try_root<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<Root<Self>> {
+        Root::try_new(cx, self)
+    }
+
     #[deprecated(since = "TBD", note = "use `Object::method()` instead")]
     fn call_method_with<'a, C, K>(&self, cx: &mut C, method: K) -> NeonResult<CallOptions<'a>>
     where
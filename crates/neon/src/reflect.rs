@@ -1,10 +1,13 @@
 //! Exposes JavaScript's reflection API to Rust.
 
+use std::fmt::Write;
+
 use crate::{
     context::Context,
     handle::Handle,
-    result::JsResult,
-    types::{build, private::ValueInternal, JsString, JsValue},
+    object::Object,
+    result::{JsResult, NeonResult, Throw},
+    types::{build, private::ValueInternal, JsFunction, JsString, JsValue, Value},
 };
 
 pub fn eval<'a, 'b, C: Context<'a>>(
@@ -16,3 +19,133 @@ pub fn eval<'a, 'b, C: Context<'a>>(
         crate::sys::string::run_script(out, env, script.to_local())
     })
 }
+
+/// Evaluates a script like [`eval`], recording `name` as the script's filename.
+///
+/// The name is reported as the source location of code in the script, for example in the
+/// [`stack`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/stack)
+/// of errors that it throws. The name is attached with a
+/// [`//# sourceURL`](https://developer.mozilla.org/en-US/docs/Tools/Debugger/How_to/Debug_eval_sources)
+/// directive appended to the source, so any whitespace in `name` is percent-encoded.
+///
+/// ```
+/// # use neon::prelude::*;
+/// # fn foo(mut cx: FunctionContext) -> JsResult<JsValue> {
+/// let src = cx.string("throw new Error('oops')");
+///
+/// // The error's stack includes `config.js:1:7`
+/// let result = cx.try_catch(|cx| neon::reflect::run_script(cx, src, "config.js"));
+/// # Ok(result.unwrap_err())
+/// # }
+/// ```
+pub fn run_script<'a, 'b, C: Context<'a>>(
+    cx: &mut C,
+    src: Handle<'b, JsString>,
+    name: &str,
+) -> JsResult<'a, JsValue> {
+    let mut script = src.value(cx);
+
+    script.push_str("\n//# sourceURL=");
+
+    for c in name.chars() {
+        if c.is_whitespace() {
+            let mut buf = [0; 4];
+
+            for b in c.encode_utf8(&mut buf).bytes() {
+                let _ = write!(script, "%{b:02X}");
+            }
+        } else {
+            script.push(c);
+        }
+    }
+
+    let script = JsString::new(cx, script);
+
+    eval(cx, script)
+}
+
+/// Returns the prototype of `obj`, equivalent to the JavaScript expression
+/// [`Object.getPrototypeOf(obj)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getPrototypeOf).
+///
+/// Objects without a prototype return `null`.
+pub fn get_prototype_of<'a, 'b, C: Context<'a>, O: Object>(
+    cx: &mut C,
+    obj: Handle<'b, O>,
+) -> JsResult<'a, JsValue> {
+    let env = cx.env().to_raw();
+    build(cx.env(), |out| unsafe {
+        crate::sys::object::get_prototype(out, env, obj.to_local())
+    })
+}
+
+/// Sets the prototype of `obj` to `proto`, equivalent to the JavaScript expression
+/// [`Object.setPrototypeOf(obj, proto)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/setPrototypeOf).
+///
+/// The prototype may be an object or `null`. Throws a `TypeError` if `proto` is neither,
+/// or if the prototype of `obj` cannot be changed, such as when `obj` is not extensible.
+///
+/// ```
+/// # use neon::prelude::*;
+/// // Creates an object that is an `instanceof` the given class
+/// fn create_instance(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let class: Handle<JsFunction> = cx.argument(0)?;
+///     let proto: Handle<JsValue> = class.prop(&mut cx, "prototype").get()?;
+///     let obj = cx.empty_object();
+///
+///     neon::reflect::set_prototype_of(&mut cx, obj, proto)?;
+///
+///     Ok(obj)
+/// }
+/// ```
+pub fn set_prototype_of<'a, 'b, C: Context<'a>, O: Object, P: Value>(
+    cx: &mut C,
+    obj: Handle<'b, O>,
+    proto: Handle<'b, P>,
+) -> NeonResult<()> {
+    // Arguments must be tied to the lifetime of the context
+    let obj: Handle<O> = Handle::new_internal(unsafe { O::from_local(cx.env(), obj.to_local()) });
+    let proto: Handle<P> =
+        Handle::new_internal(unsafe { P::from_local(cx.env(), proto.to_local()) });
+    let cx = cx.cx_mut();
+    let object: Handle<JsFunction> = cx.global("Object")?;
+
+    object
+        .method(cx, "setPrototypeOf")?
+        .arg(obj)?
+        .arg(proto)?
+        .exec()
+}
+
+/// Indicates whether `value` is an instance of `constructor`, equivalent to the JavaScript
+/// expression
+/// [`value instanceof constructor`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/instanceof).
+///
+/// Unlike a [downcast](Handle::downcast), which only recognizes built-in types, this
+/// works with any constructor, including subclasses and user-defined classes. Throws
+/// if the check throws, for example from a custom
+/// [`Symbol.hasInstance`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/hasInstance)
+/// method.
+///
+/// ```
+/// # use neon::prelude::*;
+/// fn is_buffer(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+///     let value: Handle<JsValue> = cx.argument(0)?;
+///     let buffer: Handle<JsFunction> = cx.global("Buffer")?;
+///     let result = neon::reflect::instance_of(&mut cx, value, buffer)?;
+///
+///     Ok(cx.boolean(result))
+/// }
+/// ```
+pub fn instance_of<'a, C: Context<'a>, V: Value>(
+    cx: &mut C,
+    value: Handle<V>,
+    constructor: Handle<JsFunction>,
+) -> NeonResult<bool> {
+    let env = cx.env().to_raw();
+
+    // `napi_instanceof` only fails after throwing an exception
+    unsafe {
+        crate::sys::tag::instance_of(env, value.to_local(), constructor.to_local())
+            .map_err(|_| Throw::new())
+    }
+}
@@ -97,3 +97,35 @@ where
         self.or_else(|err| cx.throw(err))
     }
 }
+
+/// Converts a Rust error into a thrown JavaScript exception.
+///
+/// A blanket implementation is provided for every type that implements [`Display`],
+/// throwing an `Error` with the displayed message. Functions exported with
+/// [`#[neon::export]`](crate::export) may return `Result<T, E>` for any `E: IntoThrow`.
+/// Error types that also implement [`TryIntoJs`](crate::types::extract::TryIntoJs)
+/// are converted with that trait instead.
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::result::IntoThrow;
+///
+/// fn parse_port(mut cx: FunctionContext) -> JsResult<JsNumber> {
+///     let port = cx.argument::<JsString>(0)?.value(&mut cx);
+///
+///     match port.parse::<u16>() {
+///         Ok(port) => Ok(cx.number(port)),
+///         Err(err) => err.throw(&mut cx),
+///     }
+/// }
+/// ```
+pub trait IntoThrow {
+    /// Throws `self` as a JavaScript exception.
+    fn throw<'a, T, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T>;
+}
+
+impl<E: Display> IntoThrow for E {
+    fn throw<'a, T, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T> {
+        cx.throw_error(self.to_string())
+    }
+}
@@ -74,3 +74,28 @@ pub unsafe fn size(env: Env, buf: Local) -> usize {
 
     size
 }
+
+#[cfg(feature = "napi-7")]
+/// # Safety
+/// * Caller must ensure `env` and `buf` are valid
+/// * No slices into the buffer's data may be alive
+pub unsafe fn detach(env: Env, buf: Local) -> Result<(), napi::Status> {
+    match napi::detach_arraybuffer(env, buf) {
+        Err(err @ napi::Status::DetachableArraybufferExpected) => Err(err),
+        status => {
+            status.unwrap();
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "napi-7")]
+/// # Safety
+/// * Caller must ensure `env` and `buf` are valid
+pub unsafe fn is_detached(env: Env, buf: Local) -> bool {
+    let mut result = false;
+
+    napi::is_detached_arraybuffer(env, buf, &mut result as *mut _).unwrap();
+
+    result
+}
@@ -19,6 +19,8 @@ mod napi1 {
 
             fn create_object(env: Env, result: *mut Value) -> Status;
 
+            fn create_symbol(env: Env, description: Value, result: *mut Value) -> Status;
+
             fn get_value_bool(env: Env, value: Value, result: *mut bool) -> Status;
 
             fn get_value_double(env: Env, value: Value, result: *mut f64) -> Status;
@@ -31,6 +33,10 @@ mod napi1 {
 
             fn coerce_to_string(env: Env, value: Value, result: *mut Value) -> Status;
 
+            fn coerce_to_number(env: Env, value: Value, result: *mut Value) -> Status;
+
+            fn coerce_to_bool(env: Env, value: Value, result: *mut Value) -> Status;
+
             fn throw(env: Env, error: Value) -> Status;
 
             fn create_error(env: Env, code: Value, msg: Value, result: *mut Value) -> Status;
@@ -58,6 +64,9 @@ mod napi1 {
             fn is_array(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_promise(env: Env, value: Value, result: *mut bool) -> Status;
 
+            fn instanceof(env: Env, object: Value, constructor: Value, result: *mut bool)
+                -> Status;
+
             fn get_value_string_utf8(
                 env: Env,
                 value: Value,
@@ -88,6 +97,13 @@ mod napi1 {
                 result: *mut Value,
             ) -> Status;
 
+            fn create_string_utf16(
+                env: Env,
+                str: *const u16,
+                length: usize,
+                result: *mut Value,
+            ) -> Status;
+
             fn create_arraybuffer(
                 env: Env,
                 byte_length: usize,
@@ -128,6 +144,14 @@ mod napi1 {
                 result: *mut Value,
             ) -> Status;
 
+            fn create_buffer_copy(
+                env: Env,
+                length: usize,
+                data: *const c_void,
+                result_data: *mut *mut c_void,
+                result: *mut Value,
+            ) -> Status;
+
             fn get_buffer_info(
                 env: Env,
                 value: Value,
@@ -182,10 +206,25 @@ mod napi1 {
 
             fn get_property(env: Env, object: Value, key: Value, result: *mut Value) -> Status;
 
+            fn get_prototype(env: Env, object: Value, result: *mut Value) -> Status;
+
             fn set_element(env: Env, object: Value, index: u32, value: Value) -> Status;
 
             fn get_element(env: Env, object: Value, index: u32, result: *mut Value) -> Status;
 
+            fn delete_property(env: Env, object: Value, key: Value, result: *mut bool) -> Status;
+
+            fn has_property(env: Env, object: Value, key: Value, result: *mut bool) -> Status;
+
+            fn has_own_property(env: Env, object: Value, key: Value, result: *mut bool) -> Status;
+
+            fn define_properties(
+                env: Env,
+                object: Value,
+                property_count: usize,
+                properties: *const PropertyDescriptor,
+            ) -> Status;
+
             fn escape_handle(
                 env: Env,
                 scope: EscapableHandleScope,
@@ -258,6 +297,21 @@ mod napi1 {
     );
 }
 
+#[cfg(feature = "napi-3")]
+mod napi3 {
+    use super::super::types::*;
+    use std::os::raw::c_void;
+
+    generate!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+        extern "C" {
+            fn add_env_cleanup_hook(env: Env, fun: CleanupHook, arg: *mut c_void) -> Status;
+
+            fn remove_env_cleanup_hook(env: Env, fun: CleanupHook, arg: *mut c_void) -> Status;
+        }
+    );
+}
+
 #[cfg(feature = "napi-4")]
 mod napi4 {
     use super::super::types::*;
@@ -387,6 +441,20 @@ mod napi6 {
     );
 }
 
+#[cfg(feature = "napi-7")]
+mod napi7 {
+    use super::super::types::*;
+
+    generate!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "napi-7")))]
+        extern "C" {
+            fn detach_arraybuffer(env: Env, arraybuffer: Value) -> Status;
+
+            fn is_detached_arraybuffer(env: Env, value: Value, result: *mut bool) -> Status;
+        }
+    );
+}
+
 #[cfg(feature = "napi-8")]
 mod napi8 {
     use super::super::types::*;
@@ -408,12 +476,16 @@ mod napi8 {
 }
 
 pub use napi1::*;
+#[cfg(feature = "napi-3")]
+pub use napi3::*;
 #[cfg(feature = "napi-4")]
 pub use napi4::*;
 #[cfg(feature = "napi-5")]
 pub use napi5::*;
 #[cfg(feature = "napi-6")]
 pub use napi6::*;
+#[cfg(feature = "napi-7")]
+pub use napi7::*;
 #[cfg(feature = "napi-8")]
 pub use napi8::*;
 
@@ -456,6 +528,9 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
 
     napi1::load(&host);
 
+    #[cfg(feature = "napi-3")]
+    napi3::load(&host);
+
     #[cfg(feature = "napi-4")]
     napi4::load(&host);
 
@@ -465,6 +540,9 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
     #[cfg(feature = "napi-6")]
     napi6::load(&host);
 
+    #[cfg(feature = "napi-7")]
+    napi7::load(&host);
+
     #[cfg(feature = "napi-8")]
     napi8::load(&host);
 
@@ -88,6 +88,11 @@ pub type Callback = Option<unsafe extern "C" fn(env: Env, info: CallbackInfo) ->
 pub type Finalize =
     Option<unsafe extern "C" fn(env: Env, finalize_data: *mut c_void, finalize_hint: *mut c_void)>;
 
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+#[cfg(feature = "napi-3")]
+/// [`napi_cleanup_hook`](https://nodejs.org/api/n-api.html#napi_cleanup_hook)
+pub type CleanupHook = Option<unsafe extern "C" fn(arg: *mut c_void)>;
+
 #[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
 #[cfg(feature = "napi-4")]
 /// [`napi_threadsafe_function_call_js`](https://nodejs.org/api/n-api.html#napi_threadsafe_function_call_js)
@@ -218,6 +223,7 @@ pub struct KeyFilter(pub ::std::os::raw::c_uint);
 impl KeyFilter {
     pub const ALL_PROPERTIES: KeyFilter = KeyFilter(0);
     pub const WRITABLE: KeyFilter = KeyFilter(1);
+    pub const ENUMERABLE: KeyFilter = KeyFilter(2);
     pub const CONFIGURABLE: KeyFilter = KeyFilter(4);
     pub const SKIP_STRINGS: KeyFilter = KeyFilter(8);
     pub const SKIP_SYMBOLS: KeyFilter = KeyFilter(16);
@@ -257,6 +263,50 @@ impl std::ops::BitAndAssign for KeyFilter {
     }
 }
 
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+/// [`napi_property_attributes`](https://nodejs.org/api/n-api.html#napi_property_attributes)
+pub struct PropertyAttributes(pub ::std::os::raw::c_uint);
+
+#[allow(dead_code)]
+impl PropertyAttributes {
+    pub const DEFAULT: PropertyAttributes = PropertyAttributes(0);
+    pub const WRITABLE: PropertyAttributes = PropertyAttributes(1);
+    pub const ENUMERABLE: PropertyAttributes = PropertyAttributes(2);
+    pub const CONFIGURABLE: PropertyAttributes = PropertyAttributes(4);
+}
+
+impl std::ops::BitOr<PropertyAttributes> for PropertyAttributes {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        PropertyAttributes(self.0 | other.0)
+    }
+}
+
+impl std::ops::BitOrAssign for PropertyAttributes {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: PropertyAttributes) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-1")))]
+/// [`napi_property_descriptor`](https://nodejs.org/api/n-api.html#napi_property_descriptor)
+pub struct PropertyDescriptor {
+    pub utf8name: *const ::std::os::raw::c_char,
+    pub name: Value,
+    pub method: Callback,
+    pub getter: Callback,
+    pub setter: Callback,
+    pub value: Value,
+    pub attributes: PropertyAttributes,
+    pub data: *mut c_void,
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[doc(hidden)]
@@ -28,6 +28,24 @@ pub unsafe fn uninitialized(env: Env, len: usize) -> Result<(Local, *mut u8), na
     Ok((buf.assume_init(), bytes.assume_init().cast()))
 }
 
+pub unsafe fn copy(env: Env, data: &[u8]) -> Result<Local, napi::Status> {
+    let mut buf = MaybeUninit::uninit();
+    let status = napi::create_buffer_copy(
+        env,
+        data.len(),
+        data.as_ptr().cast(),
+        std::ptr::null_mut(),
+        buf.as_mut_ptr(),
+    );
+
+    match status {
+        Err(err @ napi::Status::PendingException) => return Err(err),
+        status => status.unwrap(),
+    };
+
+    Ok(buf.assume_init())
+}
+
 #[cfg(feature = "external-buffers")]
 pub unsafe fn new_external<T>(env: Env, data: T) -> Local
 where
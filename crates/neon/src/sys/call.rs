@@ -28,6 +28,12 @@ impl Arguments {
     pub fn get(&self, i: usize) -> Option<Local> {
         self.0.get(i).cloned()
     }
+
+    #[inline]
+    /// Get all of the arguments as a slice
+    pub fn as_slice(&self) -> &[Local] {
+        &self.0
+    }
 }
 
 pub unsafe fn is_construct(env: Env, info: FunctionCallbackInfo) -> bool {
@@ -46,6 +46,13 @@ pub unsafe fn new_error(env: Env, out: &mut Local, msg: Local) {
     *out = result.assume_init();
 }
 
+pub unsafe fn new_error_with_code(env: Env, out: &mut Local, code: Local, msg: Local) {
+    let mut result = MaybeUninit::uninit();
+    napi::create_error(env, code, msg, result.as_mut_ptr()).unwrap();
+
+    *out = result.assume_init();
+}
+
 pub unsafe fn new_type_error(env: Env, out: &mut Local, msg: Local) {
     let mut result = MaybeUninit::uninit();
     napi::create_type_error(env, ptr::null_mut(), msg, result.as_mut_ptr()).unwrap();
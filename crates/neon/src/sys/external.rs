@@ -1,4 +1,4 @@
-use std::mem::MaybeUninit;
+use std::{any::Any, mem::MaybeUninit};
 
 use super::{
     bindings as napi,
@@ -6,6 +6,28 @@ use super::{
     raw::{Env, Local},
 };
 
+type BoxAny = Box<dyn Any + 'static>;
+
+/// Called with the value of a [`BoxedValue`] immediately before it is garbage collected
+pub type Finalizer = Box<dyn FnOnce(Env, BoxAny) + 'static>;
+
+/// Contents of the `napi_external` backing a `JsBox`
+pub struct BoxedValue {
+    pub value: BoxAny,
+    pub finalizer: Finalizer,
+}
+
+impl BoxedValue {
+    /// Wraps a panic payload so that it can be downcast like the value of a `JsBox`.
+    /// The payload is dropped without being finalized.
+    pub fn from_panic(panic: Box<dyn Any + Send + 'static>) -> Self {
+        Self {
+            value: panic,
+            finalizer: Box::new(|_, _| {}),
+        }
+    }
+}
+
 /// `finalize_external` is invoked immediately before a `napi_external` is garbage collected
 extern "C" fn finalize_external<T: 'static>(
     env: Env,
@@ -8,12 +8,16 @@
 //!
 //! [napi-docs]: https://nodejs.org/api/n-api.html#n_api_environment_life_cycle_apis
 
-use std::{mem::MaybeUninit, os::raw::c_void, ptr};
+use std::os::raw::c_void;
+
+#[cfg(feature = "napi-6")]
+use std::{mem::MaybeUninit, ptr};
 
 use super::{bindings as napi, raw::Env};
 
 /// # Safety
 /// `env` must point to a valid `napi_env` for this thread
+#[cfg(feature = "napi-6")]
 pub unsafe fn set_instance_data<T: Send + 'static>(env: Env, data: T) -> *mut T {
     let data = Box::into_raw(Box::new(data));
 
@@ -27,6 +31,7 @@ pub unsafe fn set_instance_data<T: Send + 'static>(env: Env, data: T) -> *mut T
 /// * Caller must ensure reference does not outlive `Env`
 /// * Return value may be `null`
 /// * `env` must point to a valid `napi_env` for this thread
+#[cfg(feature = "napi-6")]
 pub unsafe fn get_instance_data<T: Send + 'static>(env: Env) -> *mut T {
     let mut data = MaybeUninit::uninit();
 
@@ -35,6 +40,33 @@ pub unsafe fn get_instance_data<T: Send + 'static>(env: Env) -> *mut T {
     data.assume_init().cast()
 }
 
+/// Registers `hook` to be called with `arg` when the environment is torn down.
+///
+/// # Safety
+/// * `env` must point to a valid `napi_env` for this thread
+/// * `arg` must remain valid until the hook is called or removed
+pub unsafe fn add_cleanup_hook(
+    env: Env,
+    hook: unsafe extern "C" fn(*mut c_void),
+    arg: *mut c_void,
+) {
+    napi::add_env_cleanup_hook(env, Some(hook), arg).unwrap();
+}
+
+/// Unregisters a hook previously registered with `add_cleanup_hook`.
+///
+/// # Safety
+/// * `env` must point to a valid `napi_env` for this thread
+/// * `hook` and `arg` must match a registered hook that has not been called
+pub unsafe fn remove_cleanup_hook(
+    env: Env,
+    hook: unsafe extern "C" fn(*mut c_void),
+    arg: *mut c_void,
+) {
+    napi::remove_env_cleanup_hook(env, Some(hook), arg).unwrap();
+}
+
+#[cfg(feature = "napi-6")]
 unsafe extern "C" fn drop_box<T>(_env: Env, data: *mut c_void, _hint: *mut c_void) {
     drop(Box::<T>::from_raw(data.cast()));
 }
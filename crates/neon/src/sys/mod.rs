@@ -101,7 +101,7 @@ pub(crate) mod tsfn;
 pub(crate) mod date;
 
 mod debug_send_wrapper;
-#[cfg(feature = "napi-6")]
+#[cfg(feature = "napi-3")]
 pub(crate) mod lifecycle;
 
 /// Create a JavaScript `String`, panicking if unsuccessful
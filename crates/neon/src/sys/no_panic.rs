@@ -20,6 +20,7 @@ use super::{
     bindings as napi,
     debug_send_wrapper::DebugSendWrapper,
     error::fatal_error,
+    external::BoxedValue,
     raw::{Env, Local},
 };
 
@@ -257,7 +258,12 @@ unsafe fn external_from_panic(env: Env, panic: Panic) -> Local {
 
     if napi::create_external(
         env,
-        Box::into_raw(Box::new(DebugSendWrapper::new(panic))).cast(),
+        // Panics use the same layout as the value of a `JsBox`, so that they can be
+        // downcast with `JsBox<T>`
+        Box::into_raw(Box::new(DebugSendWrapper::new(BoxedValue::from_panic(
+            panic,
+        ))))
+        .cast(),
         Some(finalize_panic),
         ptr::null_mut(),
         result.as_mut_ptr(),
@@ -279,7 +285,7 @@ unsafe fn external_from_panic(env: Env, panic: Panic) -> Local {
 
 extern "C" fn finalize_panic(_env: Env, data: *mut c_void, _hint: *mut c_void) {
     unsafe {
-        drop(Box::from_raw(data.cast::<Panic>()));
+        drop(Box::from_raw(data.cast::<DebugSendWrapper<BoxedValue>>()));
     }
 }
 
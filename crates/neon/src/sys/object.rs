@@ -1,4 +1,4 @@
-use std::mem::MaybeUninit;
+use std::{mem::MaybeUninit, ptr};
 
 use super::{
     bindings as napi,
@@ -25,17 +25,58 @@ pub unsafe fn seal(env: Env, obj: Local) -> Result<(), napi::Status> {
     napi::object_seal(env, obj)
 }
 
+/// Defines a data property of `object` named by the `key` value, with the given `value`
+/// and `attributes`.
+pub unsafe fn define_value(
+    env: Env,
+    object: Local,
+    key: Local,
+    value: Local,
+    attributes: napi::PropertyAttributes,
+) -> Result<(), napi::Status> {
+    let descriptor = napi::PropertyDescriptor {
+        utf8name: ptr::null(),
+        name: key,
+        method: None,
+        getter: None,
+        setter: None,
+        value,
+        attributes,
+        data: ptr::null_mut(),
+    };
+
+    napi::define_properties(env, object, 1, &descriptor)
+}
+
 #[cfg(feature = "napi-6")]
 /// Mutates the `out` argument to refer to a `napi_value` containing the own property names of the
 /// `object` as a JavaScript Array.
 pub unsafe fn get_own_property_names(out: &mut Local, env: Env, object: Local) -> bool {
+    get_own_property_keys(
+        out,
+        env,
+        object,
+        napi::KeyFilter::ALL_PROPERTIES | napi::KeyFilter::SKIP_SYMBOLS,
+    )
+}
+
+#[cfg(feature = "napi-6")]
+/// Mutates the `out` argument to refer to a `napi_value` containing the own property keys of
+/// the `object` that match `filter`, with numbers converted to strings.
+/// Returns `false` if the keys couldn't be retrieved.
+pub unsafe fn get_own_property_keys(
+    out: &mut Local,
+    env: Env,
+    object: Local,
+    filter: napi::KeyFilter,
+) -> bool {
     let mut property_names = MaybeUninit::uninit();
 
     match napi::get_all_property_names(
         env,
         object,
         napi::KeyCollectionMode::OwnOnly,
-        napi::KeyFilter::ALL_PROPERTIES | napi::KeyFilter::SKIP_SYMBOLS,
+        filter,
         napi::KeyConversion::NumbersToStrings,
         property_names.as_mut_ptr(),
     ) {
@@ -140,6 +181,14 @@ pub unsafe fn get(out: &mut Local, env: Env, object: Local, key: Local) -> bool
     status.is_ok()
 }
 
+/// Mutates the `out` argument to refer to the prototype of `object`. Returns `false` if the
+/// prototype couldn't be retrieved, for example because a proxy trap threw an exception.
+pub unsafe fn get_prototype(out: &mut Local, env: Env, object: Local) -> bool {
+    let status = napi::get_prototype(env, object, out as *mut _);
+
+    status.is_ok()
+}
+
 /// Sets the property value of an `napi_value` object, named by another `value` `key`. Returns `true` if the set succeeded.
 ///
 /// The `out` parameter and the return value contain the same information for historical reasons,
@@ -152,3 +201,27 @@ pub unsafe fn set(out: &mut bool, env: Env, object: Local, key: Local, val: Loca
 
     *out
 }
+
+/// Deletes the property of `object` named by the `key` value. Mutates `out` to indicate whether
+/// the deletion succeeded. Returns `false` if the operation threw an exception.
+pub unsafe fn delete(out: &mut bool, env: Env, object: Local, key: Local) -> bool {
+    let status = napi::delete_property(env, object, key, out as *mut _);
+
+    status.is_ok()
+}
+
+/// Mutates `out` to indicate whether `object` or its prototype chain has a property named by the
+/// `key` value. Returns `false` if the operation threw an exception.
+pub unsafe fn has(out: &mut bool, env: Env, object: Local, key: Local) -> bool {
+    let status = napi::has_property(env, object, key, out as *mut _);
+
+    status.is_ok()
+}
+
+/// Mutates `out` to indicate whether `object` has an own property named by the `key` value.
+/// Returns `false` if the operation threw an exception.
+pub unsafe fn has_own(out: &mut bool, env: Env, object: Local, key: Local) -> bool {
+    let status = napi::has_own_property(env, object, key, out as *mut _);
+
+    status.is_ok()
+}
@@ -42,3 +42,9 @@ pub unsafe fn number_value(env: Env, p: Local) -> f64 {
     napi::get_value_double(env, p, &mut value as *mut f64).unwrap();
     value
 }
+
+/// Mutates the `out` argument provided to refer to a newly created `Local` containing a
+/// JavaScript symbol. The `description` must be a string or null.
+pub unsafe fn symbol(out: &mut Local, env: Env, description: Local) {
+    napi::create_symbol(env, description, out as *mut Local).unwrap();
+}
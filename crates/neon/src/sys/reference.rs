@@ -6,11 +6,15 @@ use super::{
 };
 
 pub unsafe fn new(env: Env, value: Local) -> napi::Ref {
+    try_new(env, value).unwrap()
+}
+
+pub unsafe fn try_new(env: Env, value: Local) -> Result<napi::Ref, napi::Status> {
     let mut result = MaybeUninit::uninit();
 
-    napi::create_reference(env, value, 1, result.as_mut_ptr()).unwrap();
+    napi::create_reference(env, value, 1, result.as_mut_ptr())?;
 
-    result.assume_init()
+    Ok(result.assume_init())
 }
 
 /// # Safety
@@ -11,6 +11,12 @@ pub unsafe fn new(out: &mut Local, env: Env, data: *const u8, len: i32) -> bool
     status.is_ok()
 }
 
+pub unsafe fn new_utf16(out: &mut Local, env: Env, data: *const u16, len: usize) -> bool {
+    let status = napi::create_string_utf16(env, data, len, out);
+
+    status.is_ok()
+}
+
 pub unsafe fn utf8_len(env: Env, value: Local) -> usize {
     let mut len = MaybeUninit::uninit();
     napi::get_value_string_utf8(env, value, ptr::null_mut(), 0, len.as_mut_ptr()).unwrap();
@@ -5,9 +5,28 @@ use super::{
 
 /// Return true if an `napi_value` `val` has the expected value type.
 unsafe fn is_type(env: Env, val: Local, expect: napi::ValueType) -> bool {
+    value_type(env, val) == expect
+}
+
+/// Returns the Node-API value type of `val`.
+pub unsafe fn value_type(env: Env, val: Local) -> napi::ValueType {
     let mut actual = napi::ValueType::Undefined;
     napi::typeof_value(env, val, &mut actual as *mut _).unwrap();
-    actual == expect
+    actual
+}
+
+/// Returns the result of the JavaScript `typeof` operator applied to `val`.
+pub unsafe fn type_of(env: Env, val: Local) -> &'static str {
+    match value_type(env, val) {
+        napi::ValueType::Undefined => "undefined",
+        napi::ValueType::Null | napi::ValueType::Object | napi::ValueType::External => "object",
+        napi::ValueType::Boolean => "boolean",
+        napi::ValueType::Number => "number",
+        napi::ValueType::String => "string",
+        napi::ValueType::Symbol => "symbol",
+        napi::ValueType::Function => "function",
+        napi::ValueType::BigInt => "bigint",
+    }
 }
 
 pub unsafe fn is_undefined(env: Env, val: Local) -> bool {
@@ -33,10 +52,27 @@ pub unsafe fn is_string(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::String)
 }
 
+/// Is `val` a JavaScript symbol?
+pub unsafe fn is_symbol(env: Env, val: Local) -> bool {
+    is_type(env, val, napi::ValueType::Symbol)
+}
+
 pub unsafe fn is_object(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::Object)
 }
 
+/// Is `val` an instance of `constructor`, as determined by the JavaScript `instanceof`
+/// operator?
+///
+/// Returns `Err` with an exception pending if the check throws.
+pub unsafe fn instance_of(env: Env, val: Local, constructor: Local) -> Result<bool, napi::Status> {
+    let mut result = false;
+
+    napi::instanceof(env, val, constructor, &mut result as *mut _)?;
+
+    Ok(result)
+}
+
 pub unsafe fn is_array(env: Env, val: Local) -> bool {
     let mut result = false;
     napi::is_array(env, val, &mut result as *mut _).unwrap();
@@ -38,7 +38,17 @@ struct Callback<T> {
 }
 
 /// Error returned when scheduling a threadsafe function with some data
-pub struct CallError;
+pub struct CallError {
+    is_closing: bool,
+}
+
+impl CallError {
+    /// Indicates the call failed because the threadsafe function is closing, for
+    /// example because the environment is shutting down
+    pub fn is_closing(&self) -> bool {
+        self.is_closing
+    }
+}
 
 impl<T: Send + 'static> ThreadsafeFunction<T> {
     /// Creates a new unbounded N-API Threadsafe Function
@@ -113,15 +123,17 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
         match status {
             Ok(()) => Ok(()),
             Err(status) => {
+                let is_closing = status == napi::Status::Closing;
+
                 // Prevent further calls to `call_threadsafe_function`
-                if status == napi::Status::Closing {
+                if is_closing {
                     *is_finalized = true;
                 }
 
                 // If the call failed, the callback won't execute
                 let _ = unsafe { Box::from_raw(callback) };
 
-                Err(CallError)
+                Err(CallError { is_closing })
             }
         }
     }
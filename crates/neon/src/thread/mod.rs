@@ -126,6 +126,42 @@ fn next_id() -> usize {
 /// of `LocalKey`, and values that implement [`Drop`] get destructed when
 /// the JavaScript thread exits, i.e. when a worker thread terminates or the main thread
 /// terminates on process exit.
+///
+/// Each instance of the addon has its own storage, so a value initialized on one worker
+/// thread is never observed by another. A worker that starts after another one
+/// terminated begins with uninitialized storage, even if it runs on the same system
+/// thread.
+///
+/// ### Mutation
+///
+/// Once initialized, the value of a `LocalKey` is never replaced, since references
+/// returned by [`get`](LocalKey::get) may be held for the lifetime of the context. Values
+/// that need to change, such as caches or lazily reconnected connection pools, should use
+/// [interior mutability](https://doc.rust-lang.org/book/ch15-05-interior-mutability.html).
+/// Since a `LocalKey` is stored in a `static`, the contained type must be [`Sync`], e.g.,
+/// a [`Mutex`](std::sync::Mutex) rather than a [`RefCell`](std::cell::RefCell):
+///
+/// ```
+/// # use neon::prelude::*;
+/// # use neon::thread::LocalKey;
+/// use std::sync::Mutex;
+///
+/// static LAST_MESSAGE: LocalKey<Mutex<Option<String>>> = LocalKey::new();
+///
+/// fn replace_last_message(mut cx: FunctionContext) -> JsResult<JsValue> {
+///     let message = cx.argument::<JsString>(0)?.value(&mut cx);
+///     let previous = LAST_MESSAGE
+///         .get_or_init_default(&mut cx)
+///         .lock()
+///         .unwrap()
+///         .replace(message);
+///
+///     match previous {
+///         Some(previous) => Ok(cx.string(previous).upcast()),
+///         None => Ok(cx.undefined().upcast()),
+///     }
+/// }
+/// ```
 #[derive(Default)]
 pub struct LocalKey<T> {
     _type: PhantomData<T>,
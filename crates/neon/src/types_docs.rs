@@ -71,11 +71,13 @@
 ///     JsString(JsString)
 ///     JsNull(JsNull)
 ///     JsUndefined(JsUndefined)
+///     JsSymbol(JsSymbol)
 ///     click JsBoolean "./struct.JsBoolean.html" "JsBoolean"
 ///     click JsNumber "./struct.JsNumber.html" "JsNumber"
 ///     click JsString "./struct.JsString.html" "JsString"
 ///     click JsNull "./struct.JsNull.html" "JsNull"
 ///     click JsUndefined "./struct.JsUndefined.html" "JsUndefined"
+///     click JsSymbol "./struct.JsSymbol.html" "JsSymbol"
 /// end
 /// JsValue-->primitives
 /// ```
@@ -85,8 +87,8 @@
 ///
 /// The primitive types are the built-in JavaScript datatypes that are not object
 /// types: [`JsBoolean`](crate::types::JsBoolean), [`JsNumber`](crate::types::JsNumber),
-/// [`JsString`](crate::types::JsString), [`JsNull`](crate::types::JsNull), and
-/// [`JsUndefined`](crate::types::JsUndefined).
+/// [`JsString`](crate::types::JsString), [`JsNull`](crate::types::JsNull),
+/// [`JsUndefined`](crate::types::JsUndefined), and [`JsSymbol`](crate::types::JsSymbol).
 ///
 /// #### Object Types
 ///
@@ -101,10 +103,16 @@
 ///     JsArray(JsArray)
 ///     JsDate(JsDate)
 ///     JsError(JsError)
+///     JsMap(JsMap)
+///     JsSet(JsSet)
+///     JsRegExp(JsRegExp)
 ///     click JsFunction "./struct.JsFunction.html" "JsFunction"
 ///     click JsArray "./struct.JsArray.html" "JsArray"
 ///     click JsDate "./struct.JsDate.html" "JsDate"
 ///     click JsError "./struct.JsError.html" "JsError"
+///     click JsMap "./struct.JsMap.html" "JsMap"
+///     click JsSet "./struct.JsSet.html" "JsSet"
+///     click JsRegExp "./struct.JsRegExp.html" "JsRegExp"
 /// end
 /// subgraph typedarrays [Typed Arrays]
 ///     JsBuffer(JsBuffer)
@@ -125,8 +133,9 @@
 ///
 /// These include several categories of object types:
 /// - **Standard object types:** [`JsFunction`](crate::types::JsFunction),
-///   [`JsArray`](crate::types::JsArray), [`JsDate`](crate::types::JsDate), and
-///   [`JsError`](crate::types::JsError).
+///   [`JsArray`](crate::types::JsArray), [`JsDate`](crate::types::JsDate),
+///   [`JsError`](crate::types::JsError), [`JsMap`](crate::types::JsMap),
+///   [`JsSet`](crate::types::JsSet), and [`JsRegExp`](crate::types::JsRegExp).
 /// - **Typed arrays:** [`JsBuffer`](crate::types::JsBuffer),
 ///   [`JsArrayBuffer`](crate::types::JsArrayBuffer), and
 ///   [`JsTypedArray<T>`](crate::types::JsTypedArray).
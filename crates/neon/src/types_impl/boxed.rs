@@ -3,6 +3,9 @@ use std::{
     ops::Deref,
 };
 
+#[cfg(feature = "napi-3")]
+use std::{cell::UnsafeCell, os::raw::c_void};
+
 use crate::{
     context::{
         internal::{ContextInternal, Env},
@@ -10,12 +13,64 @@ use crate::{
     },
     handle::{internal::TransparentNoCopyWrapper, Handle},
     object::Object,
-    sys::{external, raw},
+    sys::{
+        external::{self, BoxedValue, Finalizer},
+        raw,
+    },
     types::{boxed::private::JsBoxInner, private::ValueInternal, Value},
 };
 
+#[cfg(feature = "napi-3")]
+use crate::sys::lifecycle;
+
 type BoxAny = Box<dyn Any + 'static>;
 
+// Value of a `JsBox` created with `JsBox::new_with_drop`. It is `None` once the value
+// has been dropped by the environment cleanup hook.
+#[cfg(feature = "napi-3")]
+struct DropOnCleanup<T>(UnsafeCell<Option<T>>);
+
+#[cfg(feature = "napi-3")]
+impl<T> DropOnCleanup<T> {
+    fn get(&self) -> Option<&T> {
+        // Safety: The value is only removed by `take`, which requires that no
+        // references returned by this method are live
+        unsafe { (*self.0.get()).as_ref() }
+    }
+
+    // Safety: No references returned by `get` may be live
+    unsafe fn take(&self) -> Option<T> {
+        (*self.0.get()).take()
+    }
+}
+
+// Drops the value of a `JsBox` created with `JsBox::new_with_drop` when the environment
+// is torn down before the `JsBox` is garbage collected. The `napi_external` still owns
+// the emptied `DropOnCleanup`.
+//
+// Safety: Cleanup hooks run after all JavaScript has stopped, outside of any `Context`,
+// so no handle to the `JsBox` can be dereferenced concurrently. Later attempts to
+// create a handle, e.g. from a `Root`, fail because the value is gone.
+#[cfg(feature = "napi-3")]
+unsafe extern "C" fn drop_on_cleanup<T: 'static>(arg: *mut c_void) {
+    drop((*(arg as *const DropOnCleanup<T>)).take());
+}
+
+// Returns a pointer to the `T` owned by a `JsBox`, or `None` if the value is not a `T`
+// or was dropped when the environment was torn down
+fn raw_data<T: 'static>(value: &BoxAny) -> Option<*const T> {
+    if let Some(v) = value.downcast_ref::<T>() {
+        return Some(v);
+    }
+
+    #[cfg(feature = "napi-3")]
+    if let Some(v) = value.downcast_ref::<DropOnCleanup<T>>() {
+        return v.get().map(|v| v as *const T);
+    }
+
+    None
+}
+
 mod private {
     pub struct JsBoxInner<T: 'static> {
         pub(super) local: crate::sys::raw::Local,
@@ -49,7 +104,8 @@ mod private {
 /// Values contained by a `JsBox` must implement the `Finalize` trait. `Finalize::finalize`
 /// will execute with the value in a `JsBox` immediately before the `JsBox` is garbage
 /// collected. If no additional finalization is necessary, an emply implementation may
-/// be provided.
+/// be provided. Alternatively, [`JsBox::new_with_finalizer`] accepts a closure to call
+/// in place of `Finalize::finalize`, for values that do not implement `Finalize`.
 ///
 ///
 /// ## `Deref` behavior
@@ -158,10 +214,10 @@ impl<T: 'static> std::fmt::Debug for JsBox<T> {
     }
 }
 
-// Attempt to use a `napi_value` as a `napi_external` to unwrap a `BoxAny>
+// Attempt to use a `napi_value` as a `napi_external` to unwrap a `BoxedValue`
 /// Safety: `local` must be a `napi_value` that is valid for the lifetime `'a`.
-unsafe fn maybe_external_deref<'a>(env: Env, local: raw::Local) -> Option<&'a BoxAny> {
-    external::deref::<BoxAny>(env.to_raw(), local).map(|v| &*v)
+unsafe fn maybe_external_deref<'a>(env: Env, local: raw::Local) -> Option<&'a BoxedValue> {
+    external::deref::<BoxedValue>(env.to_raw(), local).map(|v| &*v)
 }
 
 // Custom `Clone` implementation since `T` might not be `Clone`
@@ -193,15 +249,14 @@ impl<T: 'static> ValueInternal for JsBox<T> {
     fn is_typeof<Other: Value>(cx: &mut Cx, other: &Other) -> bool {
         let data = unsafe { maybe_external_deref(cx.env(), other.to_local()) };
 
-        data.map(|v| v.is::<T>()).unwrap_or(false)
+        data.and_then(|v| raw_data::<T>(&v.value)).is_some()
     }
 
     fn downcast<Other: Value>(cx: &mut Cx, other: &Other) -> Option<Self> {
         let local = other.to_local();
         let data = unsafe { maybe_external_deref(cx.env(), local) };
 
-        // Attempt to downcast the `Option<&BoxAny>` to `Option<*const T>`
-        data.and_then(|v| v.downcast_ref())
+        data.and_then(|v| raw_data(&v.value))
             .map(|raw_data| Self(JsBoxInner { local, raw_data }))
     }
 
@@ -210,10 +265,9 @@ impl<T: 'static> ValueInternal for JsBox<T> {
     }
 
     unsafe fn from_local(env: Env, local: raw::Local) -> Self {
-        let raw_data = unsafe { maybe_external_deref(env, local) }
-            .expect("Failed to unwrap napi_external as Box<Any>")
-            .downcast_ref()
-            .expect("Failed to downcast Any");
+        let data = unsafe { maybe_external_deref(env, local) }
+            .expect("Failed to unwrap napi_external as BoxedValue");
+        let raw_data = raw_data(&data.value).expect("Failed to downcast Any");
 
         Self(JsBoxInner { local, raw_data })
     }
@@ -238,22 +292,150 @@ impl<T: Finalize + 'static> JsBox<T> {
     where
         C: Context<'a>,
         T: 'static,
+    {
+        // Calls the trait method `Finalize::finalize` of the contained value `T`
+        // immediately before the `JsBox` is garbage collected.
+        JsBox::new_with_finalizer(cx, value, |cx, data: T| data.finalize(cx))
+    }
+
+    /// Constructs a new `JsBox` containing `value`, like [`JsBox::new`], but with a
+    /// fallback that drops `value` when the JavaScript environment is torn down.
+    ///
+    /// Garbage collection is not guaranteed to run before the environment (e.g., a
+    /// worker thread) shuts down. This constructor is an opt-in for values that hold
+    /// resources requiring deterministic cleanup, such as file handles or locks.
+    ///
+    /// Exactly one of the following happens, whichever comes first:
+    ///
+    /// - The `JsBox` is garbage collected, and [`Finalize::finalize`] is called with the
+    ///   value, as with [`JsBox::new`].
+    /// - The environment is torn down, and the value is dropped with its
+    ///   [`Drop`] implementation. `Finalize::finalize` is _not_ called, since JavaScript
+    ///   can no longer execute.
+    ///
+    /// The cleanup hook moves the value out of the `JsBox` before dropping it, so it is
+    /// never finalized or dropped twice, even if Node-API later runs the garbage
+    /// collection finalizer of the `JsBox` as part of tearing down the environment.
+    ///
+    /// After the value has been dropped, downcasting to `JsBox<T>` fails and
+    /// [`Root::into_inner`](crate::handle::Root::into_inner) panics. This can only be
+    /// observed by code that runs during teardown, such as the finalizers of other
+    /// values holding a [`Root`](crate::handle::Root) to this `JsBox`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use std::{fs::File, io::Write};
+    ///
+    /// struct Log(File);
+    ///
+    /// impl Finalize for Log {}
+    ///
+    /// impl Drop for Log {
+    ///     fn drop(&mut self) {
+    ///         let _ = self.0.flush();
+    ///     }
+    /// }
+    ///
+    /// fn open_log(mut cx: FunctionContext) -> JsResult<JsBox<Log>> {
+    ///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///     let file = File::create(path).or_else(|err| cx.throw_error(err.to_string()))?;
+    ///
+    ///     Ok(JsBox::new_with_drop(&mut cx, Log(file)))
+    /// }
+    /// ```
+    #[cfg(feature = "napi-3")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-3")))]
+    pub fn new_with_drop<'a, C>(cx: &mut C, value: T) -> Handle<'a, JsBox<T>>
+    where
+        C: Context<'a>,
+    {
+        let env = cx.env().to_raw();
+        let data = Box::new(DropOnCleanup(UnsafeCell::new(Some(value))));
+        // Since this value was just constructed, we know it is `Some`
+        let raw_data = data.get().unwrap() as *const T;
+        let hook_arg = &*data as *const DropOnCleanup<T> as *mut c_void;
+
+        let finalizer: Finalizer = Box::new(|env, data| {
+            let data = data.downcast::<DropOnCleanup<T>>().unwrap();
+            let hook_arg = &*data as *const DropOnCleanup<T> as *mut c_void;
+
+            // The value is gone if it was dropped when the environment was torn down,
+            // in which case the cleanup hook has already run
+            if let Some(value) = data.0.into_inner() {
+                unsafe { lifecycle::remove_cleanup_hook(env, drop_on_cleanup::<T>, hook_arg) };
+
+                Cx::with_context(Env::from(env), move |mut cx| value.finalize(&mut cx));
+            }
+        });
+
+        let boxed = JsBox::create(cx, data, raw_data, finalizer);
+
+        // The `napi_external` owns the value until the `JsBox` is garbage collected,
+        // at which point its finalizer removes the hook
+        unsafe { lifecycle::add_cleanup_hook(env, drop_on_cleanup::<T>, hook_arg) };
+
+        boxed
+    }
+}
+
+impl<T: 'static> JsBox<T> {
+    /// Constructs a new `JsBox` containing `value`, calling `finalizer` with the value
+    /// immediately before the `JsBox` is garbage collected.
+    ///
+    /// Unlike [`JsBox::new`], the value is not required to implement [`Finalize`]. This
+    /// is useful for boxing types from other crates without wrapping them in a newtype.
+    /// Like [`Finalize::finalize`], the finalizer is called on the JavaScript thread.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use std::fs::File;
+    ///
+    /// fn open_file(mut cx: FunctionContext) -> JsResult<JsBox<File>> {
+    ///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///     let file = File::open(&path).or_else(|err| cx.throw_error(err.to_string()))?;
+    ///
+    ///     Ok(JsBox::new_with_finalizer(&mut cx, file, move |_cx, file| {
+    ///         eprintln!("Closing {path}");
+    ///         drop(file);
+    ///     }))
+    /// }
+    /// ```
+    pub fn new_with_finalizer<'a, C, F>(cx: &mut C, value: T, finalizer: F) -> Handle<'a, JsBox<T>>
+    where
+        C: Context<'a>,
+        F: FnOnce(&mut Cx, T) + 'static,
+    {
+        let v = Box::new(value);
+        let raw_data = &*v as *const T;
+        let finalizer: Finalizer = Box::new(move |env, value| {
+            // Downcast the `BoxAny` and move the type out of the `Box`
+            let value = *value.downcast::<T>().unwrap();
+
+            Cx::with_context(Env::from(env), move |mut cx| finalizer(&mut cx, value));
+        });
+
+        JsBox::create(cx, v, raw_data, finalizer)
+    }
+
+    // Creates the `napi_external` backing a `JsBox`. `raw_data` must point to the `T`
+    // owned by `value`.
+    fn create<'a, C>(
+        cx: &mut C,
+        value: BoxAny,
+        raw_data: *const T,
+        finalizer: Finalizer,
+    ) -> Handle<'a, JsBox<T>>
+    where
+        C: Context<'a>,
     {
         // This function will execute immediately before the `JsBox` is garbage collected.
-        // It unwraps the `napi_external`, downcasts the `BoxAny` and moves the type
-        // out of the `Box`. Lastly, it calls the trait method `Finalize::fianlize` of the
-        // contained value `T`.
-        fn finalizer<U: Finalize + 'static>(env: raw::Env, data: BoxAny) {
-            let data = *data.downcast::<U>().unwrap();
-            let env = Env::from(env);
-
-            Cx::with_context(env, move |mut cx| data.finalize(&mut cx));
+        // It unwraps the `napi_external` and passes the `BoxAny` to the finalizer.
+        fn finalize_external(env: raw::Env, data: BoxedValue) {
+            (data.finalizer)(env, data.value);
         }
 
-        let v = Box::new(value) as BoxAny;
-        // Since this value was just constructed, we know it is `T`
-        let raw_data = &*v as *const dyn Any as *const T;
-        let local = unsafe { external::create(cx.env().to_raw(), v, finalizer::<T>) };
+        let data = BoxedValue { value, finalizer };
+        let local = unsafe { external::create(cx.env().to_raw(), data, finalize_external) };
 
         Handle::new_internal(Self(JsBoxInner { local, raw_data }))
     }
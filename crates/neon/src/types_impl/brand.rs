@@ -0,0 +1,82 @@
+// Brand checks for built-in classes that Node-API cannot identify directly, e.g. `Map`.
+//
+// An `instanceof` check is not a brand check: it can be fooled by
+// `Object.create(Map.prototype)`, rejects objects from other realms and reads
+// `globalThis.Map`, which user code may replace. Instead, a built-in accessor of the
+// class, such as the `Map.prototype.size` getter, is called with the value as `this`.
+// Built-in accessors throw a `TypeError` unless `this` has the internal slots of their
+// class. The original accessors are cached, so later changes to the globals do not
+// affect the check.
+
+use crate::{
+    context::{global_function, internal::ContextInternal, Context, Cx},
+    handle::Handle,
+    object::Object,
+    result::JsResult,
+    sys::{self, raw},
+    types::{JsBoolean, JsFunction, JsObject, JsValue},
+};
+
+fn global_prototype_getter<'cx>(
+    cx: &mut Cx<'cx>,
+    global: &str,
+    name: &str,
+) -> JsResult<'cx, JsFunction> {
+    let prototype: Handle<JsObject> = cx.global::<JsFunction>(global)?.get(cx, "prototype")?;
+    let name = cx.string(name);
+    let descriptor: Handle<JsObject> = cx
+        .global::<JsFunction>("Object")?
+        .method(cx, "getOwnPropertyDescriptor")?
+        .args((prototype, name))?
+        .call()?;
+
+    descriptor.get(cx, "get")
+}
+
+global_function!(map_size, |cx| global_prototype_getter(cx, "Map", "size"));
+global_function!(set_size, |cx| global_prototype_getter(cx, "Set", "size"));
+global_function!(regexp_global, |cx| global_prototype_getter(
+    cx, "RegExp", "global"
+));
+
+// Calls the accessor returned by `getter` with `value` as `this`, returning `None` if
+// it throws. Without calling `getter`, returns `None` if `value` is not an object or an
+// exception is already pending, so that a pending exception is never cleared.
+fn call_getter<'cx>(
+    cx: &mut Cx<'cx>,
+    value: raw::Local,
+    getter: fn(&mut Cx<'cx>) -> JsResult<'cx, JsFunction>,
+) -> Option<Handle<'cx, JsValue>> {
+    let env = cx.env().to_raw();
+
+    unsafe {
+        if !sys::tag::is_object(env, value) || sys::error::is_throwing(env) {
+            return None;
+        }
+    }
+
+    cx.try_catch(|cx| {
+        let this = JsValue::new_internal(value);
+
+        getter(cx)?.bind(cx).this(this)?.call()
+    })
+    .ok()
+}
+
+/// Is `value` a `Map`?
+pub(crate) fn is_map(cx: &mut Cx, value: raw::Local) -> bool {
+    call_getter(cx, value, map_size).is_some()
+}
+
+/// Is `value` a `Set`?
+pub(crate) fn is_set(cx: &mut Cx, value: raw::Local) -> bool {
+    call_getter(cx, value, set_size).is_some()
+}
+
+/// Is `value` a `RegExp`?
+pub(crate) fn is_regexp(cx: &mut Cx, value: raw::Local) -> bool {
+    // Unlike other `RegExp.prototype` accessors, such as `source`, which returns
+    // `"(?:)"`, the `global` getter returns `undefined` instead of a boolean for
+    // `RegExp.prototype` itself, which is not a regular expression
+    call_getter(cx, value, regexp_global).is_some_and(|v| v.is_a::<JsBoolean, _>(cx))
+}
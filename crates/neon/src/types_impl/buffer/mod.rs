@@ -90,6 +90,10 @@ pub trait TypedArray: Value {
         C: Context<'cx>;
 
     /// Returns the size, in bytes, of the allocated binary data.
+    ///
+    /// Equivalent to the JavaScript `byteLength` property.
+    #[doc(alias = "byteLength")]
+    #[doc(alias = "byte_length")]
     fn size<'cx, C>(&self, cx: &mut C) -> usize
     where
         C: Context<'cx>;
@@ -7,7 +7,7 @@ use crate::{
     },
     handle::{internal::TransparentNoCopyWrapper, Handle},
     object::Object,
-    result::{JsResult, Throw},
+    result::{JsResult, NeonResult, Throw},
     sys::{self, raw, typedarray::TypedArrayInfo, TypedArrayType},
     types_impl::{
         buffer::{
@@ -206,10 +206,13 @@ impl TypedArray for JsBuffer {
     where
         C: Context<'cx>,
     {
-        let mut buffer = cx.buffer(slice.len())?;
-        let target = buffer.as_mut_slice(cx);
-        target.copy_from_slice(slice);
-        Ok(buffer)
+        // Allocates and copies in a single call, skipping the zero-fill of `JsBuffer::new`
+        unsafe {
+            match sys::buffer::copy(cx.env().to_raw(), slice) {
+                Ok(buf) => Ok(Handle::new_internal(Self(buf))),
+                Err(_) => Err(Throw::new()),
+            }
+        }
     }
 }
 
@@ -291,6 +294,75 @@ impl JsArrayBuffer {
         Handle::new_internal(Self(value))
     }
 
+    /// Constructs a `JsArrayBuffer` that takes ownership of the bytes of a `Vec`.
+    ///
+    /// When the **`external-buffers`** feature is enabled, the allocation is handed to the
+    /// JavaScript engine without copying and the `Vec` is dropped by a finalizer when the
+    /// buffer is garbage collected. See [`JsArrayBuffer::external`] for the compatibility
+    /// implications of external buffers.
+    ///
+    /// Without the feature, the bytes are copied into a new buffer and the `Vec` is dropped
+    /// immediately.
+    pub fn from_vec<'a, C>(cx: &mut C, data: Vec<u8>) -> JsResult<'a, Self>
+    where
+        C: Context<'a>,
+    {
+        #[cfg(feature = "external-buffers")]
+        {
+            Ok(Self::external(cx, data))
+        }
+
+        #[cfg(not(feature = "external-buffers"))]
+        {
+            Self::from_slice(cx, &data)
+        }
+    }
+
+    #[cfg(feature = "napi-7")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-7")))]
+    /// Detaches the buffer from its underlying memory, returning the byte length it had
+    /// before being detached.
+    ///
+    /// Equivalent to transferring the buffer, for example with
+    /// [`structuredClone(buf, { transfer: [buf] })`](https://developer.mozilla.org/en-US/docs/Web/API/structuredClone).
+    /// Once detached, the buffer and any typed arrays viewing it have a length of zero, and
+    /// [`TypedArray::as_slice`] returns an empty slice rather than reading freed memory.
+    ///
+    /// Throws a `TypeError` if the buffer is not detachable, such as the memory of a
+    /// `WebAssembly.Memory`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::types::buffer::TypedArray;
+    ///
+    /// fn detach(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let buf = cx.argument::<JsArrayBuffer>(0)?;
+    ///     let len = buf.detach(&mut cx)?;
+    ///
+    ///     assert!(buf.as_slice(&cx).is_empty());
+    ///
+    ///     Ok(cx.number(len as f64))
+    /// }
+    /// ```
+    pub fn detach<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<usize> {
+        let env = cx.env().to_raw();
+        let len = unsafe { sys::arraybuffer::size(env, self.to_local()) };
+
+        // Safety: Slices of the buffer borrow the context, so none can outlive detaching
+        match unsafe { sys::arraybuffer::detach(env, self.to_local()) } {
+            Ok(()) => Ok(len),
+            Err(_) => cx.throw_type_error("ArrayBuffer is not detachable"),
+        }
+    }
+
+    #[cfg(feature = "napi-7")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-7")))]
+    /// Indicates whether the buffer has been [detached](JsArrayBuffer::detach), for example
+    /// by being transferred to a worker thread.
+    pub fn is_detached<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
+        unsafe { sys::arraybuffer::is_detached(cx.env().to_raw(), self.to_local()) }
+    }
+
     /// Returns a region of this buffer.
     ///
     /// See also: [`Handle<JsArrayBuffer>::region()`](Handle::region) for a more
@@ -732,6 +804,55 @@ where
         Self::from_region(cx, &buffer.region(0, len))
     }
 
+    /// Copies the elements of the typed array into a new `Vec`.
+    ///
+    /// Only the elements viewed by this typed array are copied, starting at its
+    /// [`offset()`](JsTypedArray::offset) in the underlying [`JsArrayBuffer`]. This is the
+    /// counterpart to [`from_slice()`](JsTypedArray::from_slice).
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let values: Handle<JsFloat64Array> = cx.argument(0)?;
+    ///     let values = values.to_vec(&mut cx)?;
+    ///
+    ///     Ok(cx.number(values.iter().sum::<f64>()))
+    /// }
+    /// ```
+    pub fn to_vec<'cx, C>(&self, cx: &mut C) -> NeonResult<Vec<T>>
+    where
+        C: Context<'cx>,
+    {
+        Ok(self.as_slice(cx).to_vec())
+    }
+
+    /// Constructs a new typed array viewing the elements from index `begin` (inclusive) to
+    /// `end` (exclusive) of this typed array.
+    ///
+    /// The new typed array shares the same [`JsArrayBuffer`], so no data is copied. This is
+    /// similar to the JavaScript
+    /// [`subarray()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/subarray)
+    /// method, except that indices are not clamped or counted from the end.
+    ///
+    /// Throws a `RangeError` if `begin > end` or `end` exceeds the length of this typed array.
+    pub fn subarray<'cx, C>(&self, cx: &mut C, begin: usize, end: usize) -> JsResult<'cx, Self>
+    where
+        C: Context<'cx>,
+    {
+        let len = self.len(cx);
+
+        if begin > end || end > len {
+            return cx.throw_range_error(format!(
+                "subarray range {begin}..{end} is out of bounds for typed array of length {len}"
+            ));
+        }
+
+        let offset = self.offset(cx) + begin * std::mem::size_of::<T>();
+        let buffer = self.buffer(cx);
+
+        Self::from_region(cx, &buffer.region(offset, end - begin))
+    }
+
     /// Returns the [`JsArrayBuffer`](JsArrayBuffer) that owns the underlying storage buffer
     /// for this typed array.
     ///
@@ -748,6 +869,10 @@ where
 
     /// Returns the offset (in bytes) of the typed array from the start of its
     /// [`JsArrayBuffer`](JsArrayBuffer).
+    ///
+    /// Equivalent to the JavaScript `byteOffset` property.
+    #[doc(alias = "byteOffset")]
+    #[doc(alias = "byte_offset")]
     pub fn offset<'cx, C>(&self, cx: &mut C) -> usize
     where
         C: Context<'cx>,
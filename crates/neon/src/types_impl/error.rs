@@ -4,14 +4,15 @@ use std::panic::{catch_unwind, UnwindSafe};
 
 use crate::{
     context::{
+        global_function,
         internal::{ContextInternal, Env},
         Context, Cx,
     },
     handle::{internal::TransparentNoCopyWrapper, Handle},
     object::Object,
-    result::{NeonResult, Throw},
+    result::{JsResult, NeonResult, Throw},
     sys::{self, raw},
-    types::{build, private::ValueInternal, utf8::Utf8, Value},
+    types::{build, private::ValueInternal, utf8::Utf8, JsFunction, Value},
 };
 
 /// The type of JavaScript
@@ -84,6 +85,44 @@ impl JsError {
         })
     }
 
+    /// Creates a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// with its `code` property set to `code`, following the Node.js convention for
+    /// [error codes](https://nodejs.org/api/errors.html#errorcode).
+    ///
+    /// **See also:** [`Context::error_with_code`]
+    pub fn error_with_code<'a, C: Context<'a>, S: AsRef<str>, M: AsRef<str>>(
+        cx: &mut C,
+        code: S,
+        msg: M,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        let code = cx.string(code.as_ref());
+        let msg = cx.string(msg.as_ref());
+        build(cx.env(), |out| unsafe {
+            sys::error::new_error_with_code(
+                cx.env().to_raw(),
+                out,
+                code.to_local(),
+                msg.to_local(),
+            );
+            true
+        })
+    }
+
+    /// Creates a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class
+    /// with its [`cause`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error/cause)
+    /// property set to `cause`, for chaining errors.
+    ///
+    /// **See also:** [`Context::error_with_cause`]
+    pub fn error_with_cause<'a, C: Context<'a>, S: AsRef<str>, V: Value>(
+        cx: &mut C,
+        msg: S,
+        cause: Handle<'a, V>,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        let err = JsError::error(cx, msg)?;
+        err.prop(cx.cx_mut(), "cause").set(cause)?;
+        Ok(err)
+    }
+
     /// Creates an instance of the [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError) class.
     ///
     /// **See also:** [`Context::type_error`]
@@ -111,8 +150,266 @@ impl JsError {
             true
         })
     }
+
+    /// Indicates whether this error is an instance of the
+    /// [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError) class.
+    ///
+    /// Throws if the `instanceof` check throws, e.g. from a custom
+    /// [`Symbol.hasInstance`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Symbol/hasInstance)
+    /// method.
+    pub fn is_type_error<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<bool> {
+        self.is_kind(cx, JsErrorKind::TypeError)
+    }
+
+    /// Indicates whether this error is an instance of the
+    /// [`RangeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/RangeError) class.
+    ///
+    /// Throws if the `instanceof` check throws, e.g. from a custom
+    /// [`Symbol.hasInstance`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Symbol/hasInstance)
+    /// method.
+    pub fn is_range_error<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<bool> {
+        self.is_kind(cx, JsErrorKind::RangeError)
+    }
+
+    /// Classifies this error by the built-in error class it is an instance of.
+    ///
+    /// Instances of user-defined subclasses report the kind of the built-in class they
+    /// extend, and errors that do not extend any of the built-in
+    /// [native error](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error#error_types)
+    /// classes report [`JsErrorKind::Error`]. Throws if any of the `instanceof` checks
+    /// throws.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::types::JsErrorKind;
+    ///
+    /// fn error_status(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let err: Handle<JsError> = cx.argument(0)?;
+    ///     let status = match err.kind(&mut cx)? {
+    ///         JsErrorKind::TypeError | JsErrorKind::RangeError => 400,
+    ///         _ => 500,
+    ///     };
+    ///
+    ///     Ok(cx.number(status))
+    /// }
+    /// ```
+    pub fn kind<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<JsErrorKind> {
+        for kind in [
+            JsErrorKind::AggregateError,
+            JsErrorKind::EvalError,
+            JsErrorKind::RangeError,
+            JsErrorKind::ReferenceError,
+            JsErrorKind::SyntaxError,
+            JsErrorKind::TypeError,
+            JsErrorKind::UriError,
+        ] {
+            if self.is_kind(cx, kind)? {
+                return Ok(kind);
+            }
+        }
+
+        Ok(JsErrorKind::Error)
+    }
+
+    fn is_kind<'a, C: Context<'a>>(&self, cx: &mut C, kind: JsErrorKind) -> NeonResult<bool> {
+        let constructor = kind.constructor(cx.cx_mut())?;
+
+        // `napi_instanceof` only fails after throwing an exception
+        unsafe {
+            sys::tag::instance_of(cx.env().to_raw(), self.to_local(), constructor.to_local())
+                .map_err(|_| Throw::new())
+        }
+    }
 }
 
+/// A builder for errors shaped like the Node.js
+/// [`SystemError`](https://nodejs.org/api/errors.html#class-systemerror), such as those
+/// reported by failed file system or network operations.
+///
+/// A `SystemErrorBuilder` is created with [`Context::system_error`]. The resulting error
+/// has its `code` property set, along with `errno`, `syscall`, `path` and `dest` if they
+/// were provided. As in Node.js, the message is prefixed with the code and followed by the
+/// syscall and paths, e.g. `ENOENT: no such file or directory, open 'config.json'`.
+///
+/// ```
+/// # use neon::prelude::*;
+/// fn read_config(mut cx: FunctionContext) -> JsResult<JsString> {
+///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+///
+///     match std::fs::read_to_string(&path) {
+///         Ok(contents) => Ok(cx.string(contents)),
+///         Err(err) if err.kind() == std::io::ErrorKind::NotFound => cx
+///             .system_error("ENOENT", "no such file or directory")
+///             .syscall("open")
+///             .path(path)
+///             .throw(),
+///         Err(err) => cx.throw_error(err.to_string()),
+///     }
+/// }
+/// ```
+pub struct SystemErrorBuilder<'cx, C> {
+    cx: &'cx mut C,
+    code: String,
+    message: String,
+    errno: Option<i32>,
+    syscall: Option<String>,
+    path: Option<String>,
+    dest: Option<String>,
+}
+
+impl<'a: 'cx, 'cx, C: Context<'a>> SystemErrorBuilder<'cx, C> {
+    pub(crate) fn new(cx: &'cx mut C, code: String, message: String) -> Self {
+        Self {
+            cx,
+            code,
+            message,
+            errno: None,
+            syscall: None,
+            path: None,
+            dest: None,
+        }
+    }
+
+    /// Sets the `errno` property. Node.js reports the negated error numbers used by libuv,
+    /// e.g. `-2` for `ENOENT` on Unix.
+    pub fn errno(mut self, errno: i32) -> Self {
+        self.errno = Some(errno);
+        self
+    }
+
+    /// Sets the `syscall` property to the name of the system call that failed.
+    pub fn syscall<S: Into<String>>(mut self, syscall: S) -> Self {
+        self.syscall = Some(syscall.into());
+        self
+    }
+
+    /// Sets the `path` property to the file path involved in the failure.
+    pub fn path<S: Into<String>>(mut self, path: S) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Sets the `dest` property to the destination path, for operations such as rename.
+    pub fn dest<S: Into<String>>(mut self, dest: S) -> Self {
+        self.dest = Some(dest.into());
+        self
+    }
+
+    /// Creates the error without throwing it.
+    pub fn build(self) -> NeonResult<Handle<'a, JsError>> {
+        let (_, err) = self.into_error()?;
+
+        Ok(err)
+    }
+
+    /// Creates the error and throws it.
+    pub fn throw<T>(self) -> NeonResult<T> {
+        let (cx, err) = self.into_error()?;
+
+        cx.throw(err)
+    }
+
+    fn into_error(self) -> NeonResult<(&'cx mut C, Handle<'a, JsError>)> {
+        let mut msg = format!("{}: {}", self.code, self.message);
+
+        if let Some(syscall) = &self.syscall {
+            msg.push_str(&format!(", {syscall}"));
+        }
+
+        if let Some(path) = &self.path {
+            msg.push_str(&format!(" '{path}'"));
+        }
+
+        if let Some(dest) = &self.dest {
+            msg.push_str(&format!(" -> '{dest}'"));
+        }
+
+        let err = JsError::error_with_code(self.cx, &self.code, msg)?;
+        let cx = self.cx.cx_mut();
+
+        if let Some(errno) = self.errno {
+            err.prop(cx, "errno").set(errno)?;
+        }
+
+        if let Some(syscall) = self.syscall {
+            err.prop(cx, "syscall").set(syscall)?;
+        }
+
+        if let Some(path) = self.path {
+            err.prop(cx, "path").set(path)?;
+        }
+
+        if let Some(dest) = self.dest {
+            err.prop(cx, "dest").set(dest)?;
+        }
+
+        Ok((self.cx, err))
+    }
+}
+
+/// The built-in JavaScript error classes, as reported by [`JsError::kind`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum JsErrorKind {
+    /// An error that is not an instance of any of the other kinds.
+    Error,
+    /// An instance of [`AggregateError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/AggregateError).
+    AggregateError,
+    /// An instance of [`EvalError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/EvalError).
+    EvalError,
+    /// An instance of [`RangeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/RangeError).
+    RangeError,
+    /// An instance of [`ReferenceError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/ReferenceError).
+    ReferenceError,
+    /// An instance of [`SyntaxError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/SyntaxError).
+    SyntaxError,
+    /// An instance of [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError).
+    TypeError,
+    /// An instance of [`URIError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/URIError).
+    UriError,
+}
+
+impl JsErrorKind {
+    /// Returns the name of the corresponding JavaScript class, e.g. `"TypeError"`.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            JsErrorKind::Error => "Error",
+            JsErrorKind::AggregateError => "AggregateError",
+            JsErrorKind::EvalError => "EvalError",
+            JsErrorKind::RangeError => "RangeError",
+            JsErrorKind::ReferenceError => "ReferenceError",
+            JsErrorKind::SyntaxError => "SyntaxError",
+            JsErrorKind::TypeError => "TypeError",
+            JsErrorKind::UriError => "URIError",
+        }
+    }
+
+    // Returns the original constructor of the corresponding JavaScript class
+    fn constructor<'cx>(self, cx: &mut Cx<'cx>) -> JsResult<'cx, JsFunction> {
+        match self {
+            JsErrorKind::Error => error_constructor(cx),
+            JsErrorKind::AggregateError => aggregate_error_constructor(cx),
+            JsErrorKind::EvalError => eval_error_constructor(cx),
+            JsErrorKind::RangeError => range_error_constructor(cx),
+            JsErrorKind::ReferenceError => reference_error_constructor(cx),
+            JsErrorKind::SyntaxError => syntax_error_constructor(cx),
+            JsErrorKind::TypeError => type_error_constructor(cx),
+            JsErrorKind::UriError => uri_error_constructor(cx),
+        }
+    }
+}
+
+global_function!(error_constructor, "Error");
+global_function!(aggregate_error_constructor, "AggregateError");
+global_function!(eval_error_constructor, "EvalError");
+global_function!(range_error_constructor, "RangeError");
+global_function!(reference_error_constructor, "ReferenceError");
+global_function!(syntax_error_constructor, "SyntaxError");
+global_function!(type_error_constructor, "TypeError");
+global_function!(uri_error_constructor, "URIError");
+
 pub(crate) fn convert_panics<T, F: UnwindSafe + FnOnce() -> NeonResult<T>>(
     env: Env,
     f: F,
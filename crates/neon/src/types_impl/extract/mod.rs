@@ -47,6 +47,22 @@
 //! }
 //! ```
 //!
+//! ## Optional Trailing Arguments
+//!
+//! Arguments that the caller omits are extracted as `undefined`, so trailing [`Option`]
+//! arguments may be left off entirely and will be extracted as [`None`].
+//!
+//! ```
+//! # use neon::{prelude::*, types::extract::*};
+//! fn greet(mut cx: FunctionContext) -> JsResult<JsString> {
+//!     // Accepts either `greet(name)` or `greet(name, greeting)`
+//!     let (name, greeting): (String, Option<String>) = cx.args()?;
+//!     let greeting = greeting.as_deref().unwrap_or("Hello");
+//!
+//!     Ok(cx.string(format!("{greeting}, {name}!")))
+//! }
+//! ```
+//!
 //! ## Additional Extractors
 //!
 //! In some cases, the expected JavaScript type is ambiguous. For example, when
@@ -175,8 +191,116 @@ pub struct Date(pub f64);
 pub struct ArrayBuffer(pub Vec<u8>);
 
 /// Wrapper for converting between [`Vec<u8>`] and [`JsBuffer`](super::JsBuffer)
+///
+/// Extracting a `Buffer` copies the bytes out of the JavaScript buffer with a single
+/// copy, since JavaScript may continue to use the buffer after the extraction.
+///
+/// Converting a `Buffer` into JavaScript copies the bytes into a newly allocated
+/// `JsBuffer` by default. When the **`external-buffers`** feature is enabled, the
+/// `Vec<u8>` is moved into the JavaScript engine with
+/// [`JsBuffer::external`](super::JsBuffer::external) instead, avoiding the copy.
+/// Read the compatibility note on that method before enabling the feature.
+///
+/// ```
+/// # use neon::types::extract::Buffer;
+/// #[neon::export]
+/// fn reverse(buf: Buffer) -> Buffer {
+///     let Buffer(mut bytes) = buf;
+///
+///     bytes.reverse();
+///     Buffer(bytes)
+/// }
+/// ```
 pub struct Buffer(pub Vec<u8>);
 
+/// Properties extracted from a JavaScript
+/// [`Error`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error)
+///
+/// Extraction fails with a [`TypeExpected`] error if the value is not an `Error`.
+/// The `name` and `message` properties must be strings; `stack` is `None` if it is
+/// missing, e.g. on an error created without a stack trace.
+///
+/// ```
+/// # use neon::types::extract::JsErrorInfo;
+/// #[neon::export]
+/// fn describe_error(err: JsErrorInfo) -> String {
+///     format!("{}: {}", err.name, err.message)
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsErrorInfo {
+    /// The `name` of the error, e.g. `"TypeError"`
+    pub name: String,
+    /// The error `message`
+    pub message: String,
+    /// The `stack` trace, if present
+    pub stack: Option<String>,
+}
+
+/// Extractor that collects all remaining function arguments into a [`Vec`]
+///
+/// `Rest` may be used on its own or as the last element of a tuple passed to
+/// [`FunctionContext::args`]. Every argument after the preceding fixed arguments
+/// is converted into `T`. If there are no remaining arguments, the `Vec` is empty.
+///
+/// ```
+/// # use neon::{prelude::*, types::extract::*};
+/// fn join(mut cx: FunctionContext) -> JsResult<JsString> {
+///     // join(separator, ...parts)
+///     let (separator, Rest(parts)): (String, Rest<String>) = cx.args()?;
+///
+///     Ok(cx.string(parts.join(&separator)))
+/// }
+/// ```
+pub struct Rest<T>(pub Vec<T>);
+
+impl<'cx, T> Rest<T>
+where
+    T: TryFromJs<'cx>,
+{
+    fn from_args_at(cx: &mut FunctionContext<'cx>, start: usize) -> NeonResult<Self> {
+        let mut values = Vec::with_capacity(cx.len().saturating_sub(start));
+
+        for i in start..cx.len() {
+            let v = cx.argument::<JsValue>(i)?;
+
+            values.push(T::from_js(cx, v)?);
+        }
+
+        Ok(Rest(values))
+    }
+
+    fn from_args_opt_at(cx: &mut FunctionContext<'cx>, start: usize) -> NeonResult<Option<Self>> {
+        let mut values = Vec::with_capacity(cx.len().saturating_sub(start));
+
+        for i in start..cx.len() {
+            let v = cx.argument::<JsValue>(i)?;
+
+            match T::try_from_js(cx, v)? {
+                Ok(v) => values.push(v),
+                Err(_) => return Ok(None),
+            }
+        }
+
+        Ok(Some(Rest(values)))
+    }
+}
+
+impl<'cx, T> private::FromArgsInternal<'cx> for Rest<T>
+where
+    T: TryFromJs<'cx>,
+{
+    fn from_args(cx: &mut FunctionContext<'cx>) -> NeonResult<Self> {
+        Rest::from_args_at(cx, 0)
+    }
+
+    fn from_args_opt(cx: &mut FunctionContext<'cx>) -> NeonResult<Option<Self>> {
+        Rest::from_args_opt_at(cx, 0)
+    }
+}
+
+impl<'cx, T> FromArgs<'cx> for Rest<T> where T: TryFromJs<'cx> {}
+
 /// Trait specifying values that may be extracted from function arguments.
 ///
 /// **Note:** This trait is implemented for tuples of up to 32 values, but for
@@ -240,10 +364,56 @@ macro_rules! from_args_impl {
     }
 }
 
+// Implements `FromArgs` for a tuple of fixed arguments followed by a trailing `Rest<R>`
+macro_rules! from_args_rest_impl {
+    ($(#[$attrs:meta])? [$($ty:ident),*]) => {
+        $(#[$attrs])?
+        impl<'cx, $($ty,)* R> FromArgs<'cx> for ($($ty,)* Rest<R>,)
+        where
+            $($ty: TryFromJs<'cx>,)*
+            R: TryFromJs<'cx>,
+        {}
+
+        #[allow(non_snake_case)]
+        impl<'cx, $($ty,)* R> private::FromArgsInternal<'cx> for ($($ty,)* Rest<R>,)
+        where
+            $($ty: TryFromJs<'cx>,)*
+            R: TryFromJs<'cx>,
+        {
+            fn from_args(cx: &mut FunctionContext<'cx>) -> NeonResult<Self> {
+                let start = <[&str]>::len(&[$(stringify!($ty)),*]);
+                let [$($ty,)*] = cx.argv();
+
+                Ok((
+                    $($ty::from_js(cx, $ty)?,)*
+                    Rest::from_args_at(cx, start)?,
+                ))
+            }
+
+            fn from_args_opt(cx: &mut FunctionContext<'cx>) -> NeonResult<Option<Self>> {
+                let start = <[&str]>::len(&[$(stringify!($ty)),*]);
+                let [$($ty,)*] = cx.argv();
+
+                Ok(Some((
+                    $(match $ty::try_from_js(cx, $ty)? {
+                        Ok(v) => v,
+                        Err(_) => return Ok(None),
+                    },)*
+                    match Rest::from_args_opt_at(cx, start)? {
+                        Some(v) => v,
+                        None => return Ok(None),
+                    },
+                )))
+            }
+        }
+    }
+}
+
 macro_rules! from_args_expand {
     ($(#[$attrs:meta])? [$($head:ident),*], []) => {};
 
     ($(#[$attrs:meta])? [$($head:ident),*], [$cur:ident $(, $tail:ident)*]) => {
+        from_args_rest_impl!($(#[$attrs])? [$($head),*]);
         from_args_impl!($(#[$attrs])? [$($head,)* $cur]);
         from_args_expand!($(#[$attrs])? [$($head,)* $cur], [$($tail),*]);
     };
@@ -256,8 +426,8 @@ macro_rules! from_args {
     };
 }
 
-// Implement `FromArgs` for tuples up to length `32`. The first list is included
-// in docs and the second list is `#[doc(hidden)]`.
+// Implement `FromArgs` for tuples up to length `32`, with and without a trailing `Rest`.
+// The first list is included in docs and the second list is `#[doc(hidden)]`.
 from_args!(
     [T1, T2, T3, T4, T5, T6, T7, T8],
     [
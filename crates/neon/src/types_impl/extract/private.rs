@@ -1,4 +1,8 @@
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::SystemTime,
+};
 
 use crate::{
     context::FunctionContext,
@@ -7,7 +11,7 @@ use crate::{
     result::{NeonResult, Throw},
     types::{
         buffer::Binary,
-        extract::{ArrayBuffer, Buffer, Date, Error, TryIntoJs},
+        extract::{ArrayBuffer, Buffer, Date, Error, JsErrorInfo, TryIntoJs},
         JsTypedArray, Value,
     },
 };
@@ -81,6 +85,16 @@ where
 {
 }
 
+impl<V, S> Sealed for HashMap<String, V, S> {}
+
+impl<V> Sealed for BTreeMap<String, V> {}
+
+#[cfg(feature = "napi-5")]
+impl Sealed for SystemTime {}
+
+#[cfg(all(feature = "chrono", feature = "napi-5"))]
+impl Sealed for chrono::DateTime<chrono::Utc> {}
+
 impl<'cx, T> Sealed for Arc<T> where for<'a> &'a T: TryIntoJs<'cx> {}
 
 impl<'cx, T> Sealed for Box<T> where T: TryIntoJs<'cx> {}
@@ -99,6 +113,7 @@ impl_sealed!(
     Date,
     Buffer,
     ArrayBuffer,
+    JsErrorInfo,
     Throw,
     Error,
 );
@@ -13,14 +13,18 @@ use crate::{
     sys,
     types::{
         buffer::{Binary, TypedArray},
-        extract::{ArrayBuffer, Buffer, Date, TryFromJs, TypeExpected},
+        extract::{ArrayBuffer, Buffer, Date, JsErrorInfo, TryFromJs, TypeExpected},
         private::ValueInternal,
-        JsArrayBuffer, JsBoolean, JsBuffer, JsNumber, JsString, JsTypedArray, JsValue, Value,
+        JsArrayBuffer, JsBoolean, JsBuffer, JsError, JsNumber, JsString, JsTypedArray, JsValue,
+        Value,
     },
 };
 
 #[cfg(feature = "napi-5")]
-use crate::types::JsDate;
+use {
+    crate::types::{extract::Error, JsDate},
+    std::time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 impl<'cx, V> TryFromJs<'cx> for Handle<'cx, V>
 where
@@ -179,6 +183,61 @@ impl<'cx> TryFromJs<'cx> for Date {
     }
 }
 
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-5")))]
+#[cfg(feature = "napi-5")]
+impl<'cx> TryFromJs<'cx> for SystemTime {
+    type Error = Error;
+
+    fn try_from_js(
+        cx: &mut Cx<'cx>,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, Self::Error>> {
+        let ms = match Date::try_from_js(cx, v)? {
+            Ok(Date(ms)) => ms,
+            Err(_) => return Ok(Err(Error::type_error("expected Date"))),
+        };
+
+        if ms.is_nan() {
+            return Ok(Err(Error::range_error("invalid Date")));
+        }
+
+        // `Date` values are always integral and within `JsDate::MIN_VALUE..=JsDate::MAX_VALUE`
+        let offset = Duration::from_millis(ms.abs() as u64);
+        let time = if ms < 0.0 {
+            UNIX_EPOCH.checked_sub(offset)
+        } else {
+            UNIX_EPOCH.checked_add(offset)
+        };
+
+        Ok(time.ok_or_else(|| Error::range_error("Date is out of range for SystemTime")))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(all(feature = "chrono", feature = "napi-5"))))]
+#[cfg(all(feature = "chrono", feature = "napi-5"))]
+impl<'cx> TryFromJs<'cx> for chrono::DateTime<chrono::Utc> {
+    type Error = Error;
+
+    fn try_from_js(
+        cx: &mut Cx<'cx>,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, Self::Error>> {
+        let ms = match Date::try_from_js(cx, v)? {
+            Ok(Date(ms)) => ms,
+            Err(_) => return Ok(Err(Error::type_error("expected Date"))),
+        };
+
+        if ms.is_nan() {
+            return Ok(Err(Error::range_error("invalid Date")));
+        }
+
+        // `Date` values are always integral and within `JsDate::MIN_VALUE..=JsDate::MAX_VALUE`
+        let time = chrono::DateTime::from_timestamp_millis(ms as i64);
+
+        Ok(time.ok_or_else(|| Error::range_error("Date is out of range for DateTime")))
+    }
+}
+
 // This implementation primarily exists for macro authors. It is infallible, rather
 // than checking a type, to match the JavaScript conventions of ignoring additional
 // arguments.
@@ -252,6 +311,26 @@ impl<'cx> TryFromJs<'cx> for ArrayBuffer {
     }
 }
 
+impl<'cx> TryFromJs<'cx> for JsErrorInfo {
+    type Error = TypeExpected<JsError>;
+
+    fn try_from_js(
+        cx: &mut Cx<'cx>,
+        v: Handle<'cx, JsValue>,
+    ) -> NeonResult<Result<Self, Self::Error>> {
+        let err = match v.downcast::<JsError, _>(cx) {
+            Ok(err) => err,
+            Err(_) => return Ok(Err(Self::Error::new())),
+        };
+
+        Ok(Ok(JsErrorInfo {
+            name: err.prop(cx, "name").get()?,
+            message: err.prop(cx, "message").get()?,
+            stack: err.prop(cx, "stack").get()?,
+        }))
+    }
+}
+
 fn is_null_or_undefined<V>(cx: &mut Cx, v: Handle<V>) -> NeonResult<bool>
 where
     V: Value,
@@ -1,4 +1,8 @@
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use crate::prelude::Object;
 use crate::{
@@ -8,8 +12,8 @@ use crate::{
     types::{
         buffer::Binary,
         extract::{ArrayBuffer, Buffer, Date, TryIntoJs},
-        JsArrayBuffer, JsBoolean, JsBuffer, JsDate, JsNumber, JsString, JsTypedArray, JsUndefined,
-        JsValue, Value,
+        JsArrayBuffer, JsBoolean, JsBuffer, JsDate, JsNumber, JsObject, JsString, JsTypedArray,
+        JsUndefined, JsValue, Value,
     },
 };
 
@@ -204,6 +208,41 @@ where
     }
 }
 
+impl<'cx, V, S> TryIntoJs<'cx> for HashMap<String, V, S>
+where
+    V: TryIntoJs<'cx>,
+{
+    type Value = JsObject;
+
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        let obj = cx.empty_object();
+
+        for (k, v) in self {
+            obj.prop(cx, k.as_str()).set(v)?;
+        }
+
+        Ok(obj)
+    }
+}
+
+// Entries are set in key order, which determines the property order of the object
+impl<'cx, V> TryIntoJs<'cx> for BTreeMap<String, V>
+where
+    V: TryIntoJs<'cx>,
+{
+    type Value = JsObject;
+
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        let obj = cx.empty_object();
+
+        for (k, v) in self {
+            obj.prop(cx, k.as_str()).set(v)?;
+        }
+
+        Ok(obj)
+    }
+}
+
 impl<'cx> TryIntoJs<'cx> for bool {
     type Value = JsBoolean;
 
@@ -231,6 +270,12 @@ impl<'cx> TryIntoJs<'cx> for ArrayBuffer {
 impl<'cx> TryIntoJs<'cx> for Buffer {
     type Value = JsBuffer;
 
+    #[cfg(feature = "external-buffers")]
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        Ok(JsBuffer::external(cx, self.0))
+    }
+
+    #[cfg(not(feature = "external-buffers"))]
     fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
         JsBuffer::from_slice(cx, &self.0)
     }
@@ -243,3 +288,30 @@ impl<'cx> TryIntoJs<'cx> for Date {
         cx.date(self.0).or_throw(cx)
     }
 }
+
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-5")))]
+#[cfg(feature = "napi-5")]
+impl<'cx> TryIntoJs<'cx> for SystemTime {
+    type Value = JsDate;
+
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        // Throws a `RangeError` if the time is outside of the range of a `Date`
+        let ms = match self.duration_since(UNIX_EPOCH) {
+            Ok(offset) => offset.as_millis() as f64,
+            Err(err) => -(err.duration().as_millis() as f64),
+        };
+
+        Date(ms).try_into_js(cx)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(all(feature = "chrono", feature = "napi-5"))))]
+#[cfg(all(feature = "chrono", feature = "napi-5"))]
+impl<'cx> TryIntoJs<'cx> for chrono::DateTime<chrono::Utc> {
+    type Value = JsDate;
+
+    fn try_into_js(self, cx: &mut Cx<'cx>) -> JsResult<'cx, Self::Value> {
+        // Throws a `RangeError` if the time is outside of the range of a `Date`
+        Date(self.timestamp_millis() as f64).try_into_js(cx)
+    }
+}
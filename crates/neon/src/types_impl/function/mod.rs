@@ -39,6 +39,12 @@ pub struct BindOptions<'a, 'cx: 'a> {
 
 impl<'a, 'cx: 'a> BindOptions<'a, 'cx> {
     /// Set the value of `this` for the function call.
+    ///
+    /// Any value may be used, including primitives such as numbers and strings, as with
+    /// [`Function.prototype.call`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/call).
+    /// Strict mode functions observe primitive `this` values unchanged, while sloppy mode
+    /// functions observe them boxed in their object wrappers (e.g., `new Number(42)`) and
+    /// observe `null` or `undefined` as the global object.
     pub fn this<T: TryIntoJs<'cx>>(&mut self, this: T) -> NeonResult<&mut Self> {
         let v = this.try_into_js(self.cx)?;
         self.this = Some(v.upcast());
@@ -217,6 +223,8 @@ impl<'cx> private::TryIntoArgumentsInternal<'cx> for () {
     }
 }
 
+impl<'cx> TryIntoArguments<'cx> for () {}
+
 impl<'cx, F, O> private::TryIntoArgumentsInternal<'cx> for With<F, O>
 where
     F: FnOnce(&mut Cx) -> O,
@@ -254,6 +262,14 @@ where
 {
 }
 
+impl<'cx, V: Value> private::TryIntoArgumentsInternal<'cx> for &[Handle<'cx, V>] {
+    fn try_into_args_vec(self, _cx: &mut Cx<'cx>) -> NeonResult<private::ArgsVec<'cx>> {
+        Ok(self.iter().map(|v| v.upcast()).collect())
+    }
+}
+
+impl<'cx, V: Value> TryIntoArguments<'cx> for &[Handle<'cx, V>] {}
+
 macro_rules! impl_into_arguments_expand {
     {
         $(#[$attrs:meta])?
@@ -0,0 +1,226 @@
+use crate::{
+    context::{internal::Env, Context, Cx},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::raw,
+    types::{
+        brand, extract::TryIntoJs, private::ValueInternal, JsArray, JsFunction, JsObject, JsValue,
+        Value,
+    },
+};
+
+/// The type of JavaScript
+/// [`Map`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map)
+/// objects.
+///
+/// Unlike a plain [`JsObject`], a `JsMap` can use any JavaScript value as a key and
+/// remembers the insertion order of its entries.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsMap;
+///
+/// fn word_lengths(mut cx: FunctionContext) -> JsResult<JsMap> {
+///     let map = JsMap::new(&mut cx)?;
+///
+///     for word in ["neon", "rust", "javascript"] {
+///         map.set(&mut cx, word, word.len() as f64)?;
+///     }
+///
+///     Ok(map)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsMap(raw::Local);
+
+impl JsMap {
+    /// Creates a new empty `Map`, equivalent to the JavaScript expression `new Map()`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsMap> {
+        let cx = cx.cx_mut();
+        let map: Handle<JsFunction> = cx.global("Map")?;
+
+        map.bind(cx).construct()
+    }
+
+    /// Gets the value associated with `key`, or `undefined` if there is none.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.get(key)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/get).
+    pub fn get<'a, C, K>(&self, cx: &mut C, key: K) -> JsResult<'a, JsValue>
+    where
+        C: Context<'a>,
+        K: TryIntoJs<'a>,
+    {
+        self.method(cx.cx_mut(), "get")?.arg(key)?.call()
+    }
+
+    /// Associates `value` with `key`, replacing any existing entry for `key`.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.set(key, value)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/set).
+    pub fn set<'a, C, K, V>(&self, cx: &mut C, key: K, value: V) -> NeonResult<()>
+    where
+        C: Context<'a>,
+        K: TryIntoJs<'a>,
+        V: TryIntoJs<'a>,
+    {
+        self.method(cx.cx_mut(), "set")?
+            .arg(key)?
+            .arg(value)?
+            .exec()
+    }
+
+    /// Indicates whether the map contains an entry for `key`.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.has(key)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/has).
+    pub fn has<'a, C, K>(&self, cx: &mut C, key: K) -> NeonResult<bool>
+    where
+        C: Context<'a>,
+        K: TryIntoJs<'a>,
+    {
+        self.method(cx.cx_mut(), "has")?.arg(key)?.call()
+    }
+
+    /// Removes the entry for `key`, returning `true` if an entry was present.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.delete(key)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/delete).
+    pub fn delete<'a, C, K>(&self, cx: &mut C, key: K) -> NeonResult<bool>
+    where
+        C: Context<'a>,
+        K: TryIntoJs<'a>,
+    {
+        self.method(cx.cx_mut(), "delete")?.arg(key)?.call()
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.size`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/size).
+    pub fn size<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<u32> {
+        let size: f64 = self.prop(cx.cx_mut(), "size").get()?;
+
+        Ok(size as u32)
+    }
+
+    /// Returns an iterator over the key/value pairs of the map, in insertion order.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.entries()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/entries).
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use neon::types::JsMap;
+    /// # fn count_strings(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    /// let map: Handle<JsMap> = cx.argument(0)?;
+    /// let mut entries = map.entries(&mut cx)?;
+    /// let mut count = 0;
+    ///
+    /// while let Some(entry) = entries.next() {
+    ///     let (_key, value) = entry?;
+    ///
+    ///     if value.is_a::<JsString, _>(entries.cx()) {
+    ///         count += 1;
+    ///     }
+    /// }
+    ///
+    /// Ok(cx.number(count))
+    /// # }
+    /// ```
+    pub fn entries<'a, 'cx: 'a, C: Context<'cx>>(
+        &self,
+        cx: &'a mut C,
+    ) -> NeonResult<MapEntries<'a, 'cx, C>> {
+        let iterator = self.method(cx.cx_mut(), "entries")?.call()?;
+
+        Ok(MapEntries {
+            cx,
+            iterator,
+            done: false,
+        })
+    }
+}
+
+impl Value for JsMap {}
+
+impl Object for JsMap {}
+
+unsafe impl TransparentNoCopyWrapper for JsMap {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsMap {
+    fn name() -> &'static str {
+        "Map"
+    }
+
+    fn is_typeof<Other: Value>(cx: &mut Cx, other: &Other) -> bool {
+        brand::is_map(cx, other.to_local())
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsMap(h)
+    }
+}
+
+/// An iterator over the entries of a [`JsMap`].
+///
+/// This type is created by the [`JsMap::entries`] method.
+pub struct MapEntries<'a, 'cx: 'a, C: Context<'cx>> {
+    cx: &'a mut C,
+    iterator: Handle<'cx, JsObject>,
+    done: bool,
+}
+
+impl<'a, 'cx: 'a, C: Context<'cx>> MapEntries<'a, 'cx, C> {
+    /// Returns the context borrowed by this iterator, for use between iterations.
+    pub fn cx(&mut self) -> &mut C {
+        self.cx
+    }
+
+    fn next_entry(&mut self) -> NeonResult<Option<(Handle<'cx, JsValue>, Handle<'cx, JsValue>)>> {
+        let cx = self.cx.cx_mut();
+        let result: Handle<JsObject> = self.iterator.method(cx, "next")?.call()?;
+
+        if result.prop(cx, "done").get()? {
+            return Ok(None);
+        }
+
+        let entry: Handle<JsArray> = result.prop(cx, "value").get()?;
+        let key = entry.prop(cx, 0).get()?;
+        let value = entry.prop(cx, 1).get()?;
+
+        Ok(Some((key, value)))
+    }
+}
+
+impl<'a, 'cx: 'a, C: Context<'cx>> Iterator for MapEntries<'a, 'cx, C> {
+    type Item = NeonResult<(Handle<'cx, JsValue>, Handle<'cx, JsValue>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let entry = self.next_entry().transpose();
+
+        self.done = !matches!(entry, Some(Ok(_)));
+
+        entry
+    }
+}
+
+impl<'a, 'cx: 'a, C: Context<'cx>> std::iter::FusedIterator for MapEntries<'a, 'cx, C> {}
@@ -4,13 +4,19 @@
 #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
 pub mod bigint;
 pub(crate) mod boxed;
+pub(crate) mod brand;
 pub mod buffer;
 #[cfg(feature = "napi-5")]
 pub(crate) mod date;
 pub(crate) mod error;
 pub mod extract;
 pub mod function;
+pub(crate) mod map;
 pub(crate) mod promise;
+pub(crate) mod proxy;
+pub(crate) mod regexp;
+pub(crate) mod set;
+pub(crate) mod symbol;
 
 pub(crate) mod private;
 pub(crate) mod utf8;
@@ -25,6 +31,7 @@ use smallvec::smallvec;
 
 use crate::{
     context::{
+        global_function,
         internal::{ContextInternal, Env},
         Context, Cx, FunctionContext,
     },
@@ -36,6 +43,7 @@ use crate::{
     result::{JsResult, NeonResult, ResultExt, Throw},
     sys::{self, raw},
     types::{
+        extract::TryIntoJs,
         function::{BindOptions, CallOptions, ConstructOptions},
         private::ValueInternal,
         utf8::Utf8,
@@ -49,10 +57,18 @@ pub use self::{
         JsInt16Array, JsInt32Array, JsInt8Array, JsTypedArray, JsUint16Array, JsUint32Array,
         JsUint8Array,
     },
-    error::JsError,
+    error::{JsError, JsErrorKind, SystemErrorBuilder},
+    map::{JsMap, MapEntries},
     promise::{Deferred, JsPromise},
+    proxy::JsProxy,
+    regexp::JsRegExp,
+    set::{JsSet, SetValues},
+    symbol::JsSymbol,
 };
 
+#[cfg(feature = "napi-4")]
+pub use self::promise::{DeferredError, DeferredErrorKind};
+
 #[cfg(feature = "napi-5")]
 pub use self::date::{DateError, DateErrorKind, JsDate};
 
@@ -190,6 +206,71 @@ impl ValueInternal for JsValue {
 }
 
 impl JsValue {
+    /// Returns the result of applying the JavaScript
+    /// [`typeof`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/typeof)
+    /// operator to this value, e.g. `"string"` or `"object"`.
+    ///
+    /// As in JavaScript, the result for `null` is `"object"`.
+    pub fn type_of<'a, C: Context<'a>>(&self, cx: &mut C) -> &'static str {
+        unsafe { sys::tag::type_of(cx.env().to_raw(), self.to_local()) }
+    }
+
+    /// Returns the name of this value's constructor, equivalent to the JavaScript
+    /// expression `this.constructor.name`, e.g. `"Map"` or `"Number"`.
+    ///
+    /// Returns `"null"` or `"undefined"` for those values. If the value has no
+    /// constructor with a string name, such as an object created with
+    /// `Object.create(null)`, the result of [`type_of`](JsValue::type_of) is returned.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn expect_map(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let value: Handle<JsValue> = cx.argument(0)?;
+    ///     let name = value.class_name(&mut cx)?;
+    ///
+    ///     if name != "Map" {
+    ///         return cx.throw_type_error(format!("expected Map, got {name}"));
+    ///     }
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn class_name<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<String> {
+        let cx = cx.cx_mut();
+        let value = JsValue::new_internal(self.0);
+
+        if value.is_a::<JsNull, _>(cx) {
+            return Ok("null".to_string());
+        }
+
+        if value.is_a::<JsUndefined, _>(cx) {
+            return Ok("undefined".to_string());
+        }
+
+        let object: Handle<JsObject> = if let Ok(function) = value.downcast::<JsFunction, _>(cx) {
+            function.upcast()
+        } else {
+            // Wrap primitives in their corresponding object types so the
+            // `constructor` property is found on the prototype
+            cx.global::<JsFunction>("Object")?
+                .bind(cx)
+                .arg(value)?
+                .call()?
+        };
+
+        let constructor: Handle<JsValue> = object.prop(cx, "constructor").get()?;
+
+        if let Ok(constructor) = constructor.downcast::<JsFunction, _>(cx) {
+            let name: Handle<JsValue> = constructor.prop(cx, "name").get()?;
+
+            if let Ok(name) = name.downcast::<JsString, _>(cx) {
+                return Ok(name.value(cx));
+            }
+        }
+
+        Ok(self.type_of(cx).to_string())
+    }
+
     pub(crate) fn new_internal<'a>(value: raw::Local) -> Handle<'a, JsValue> {
         Handle::new_internal(JsValue(value))
     }
@@ -535,6 +616,10 @@ impl JsString {
 
     /// Convert this JavaScript string into a Rust [`String`].
     ///
+    /// The contents are copied without validation, relying on the JavaScript engine
+    /// to produce well-formed UTF-8. Use [`JsString::value_lossy`] to defensively
+    /// replace any invalid sequences instead.
+    ///
     /// # Example
     ///
     /// This example function expects a single JavaScript string as argument
@@ -550,14 +635,108 @@ impl JsString {
     /// }
     /// ```
     pub fn value<'a, C: Context<'a>>(&self, cx: &mut C) -> String {
+        unsafe { String::from_utf8_unchecked(self.to_utf8_bytes(cx)) }
+    }
+
+    /// Convert this JavaScript string into a Rust [`String`], replacing any invalid
+    /// UTF-8 sequences with [`U+FFFD REPLACEMENT CHARACTER`](std::char::REPLACEMENT_CHARACTER).
+    ///
+    /// Unlike [`JsString::value`], this method validates the contents returned by the
+    /// JavaScript engine and never assumes they are well-formed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use neon::prelude::*;
+    /// fn print_string_lossy(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let s = cx.argument::<JsString>(0)?.value_lossy(&mut cx);
+    ///     println!("JavaScript string contents: {}", s);
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn value_lossy<'a, C: Context<'a>>(&self, cx: &mut C) -> String {
+        String::from_utf8(self.to_utf8_bytes(cx))
+            .unwrap_or_else(|err| String::from_utf8_lossy(err.as_bytes()).into_owned())
+    }
+
+    /// Copies this JavaScript string into `buf`, replacing its previous contents.
+    ///
+    /// Unlike [`JsString::value`], this reuses the existing capacity of `buf`, so
+    /// reading many strings in a loop can amortize the cost of allocation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use neon::prelude::*;
+    /// fn count_long_strings(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let strings = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+    ///     let mut buf = String::new();
+    ///     let mut count = 0;
+    ///
+    ///     for s in strings {
+    ///         s.downcast_or_throw::<JsString, _>(&mut cx)?
+    ///             .value_into(&mut cx, &mut buf);
+    ///
+    ///         if buf.chars().count() > 10 {
+    ///             count += 1;
+    ///         }
+    ///     }
+    ///
+    ///     Ok(cx.number(count))
+    /// }
+    /// ```
+    pub fn value_into<'a, C: Context<'a>>(&self, cx: &mut C, buf: &mut String) {
+        let mut bytes = std::mem::take(buf).into_bytes();
+
+        self.write_utf8_bytes(cx, &mut bytes);
+
+        *buf = unsafe { String::from_utf8_unchecked(bytes) };
+    }
+
+    /// Convert this JavaScript string into a [`CString`](std::ffi::CString) for passing
+    /// to C APIs.
+    ///
+    /// Returns a [`NulError`](std::ffi::NulError) if the string contains an interior
+    /// `"\0"` character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use neon::prelude::*;
+    /// fn c_strlen(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let s = cx.argument::<JsString>(0)?;
+    ///     let s = s.to_cstring(&mut cx).or_else(|err| cx.throw_type_error(err.to_string()))?;
+    ///
+    ///     Ok(cx.number(s.as_bytes().len() as f64))
+    /// }
+    /// ```
+    pub fn to_cstring<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+    ) -> Result<std::ffi::CString, std::ffi::NulError> {
+        std::ffi::CString::new(self.to_utf8_bytes(cx))
+    }
+
+    fn to_utf8_bytes<'a, C: Context<'a>>(&self, cx: &mut C) -> Vec<u8> {
+        let mut buffer = Vec::new();
+
+        self.write_utf8_bytes(cx, &mut buffer);
+
+        buffer
+    }
+
+    fn write_utf8_bytes<'a, C: Context<'a>>(&self, cx: &mut C, buffer: &mut Vec<u8>) {
         let env = cx.env().to_raw();
 
         unsafe {
             let capacity = sys::string::utf8_len(env, self.to_local()) + 1;
-            let mut buffer: Vec<u8> = Vec::with_capacity(capacity);
+
+            buffer.clear();
+            buffer.reserve(capacity);
+
             let len = sys::string::data(env, buffer.as_mut_ptr(), capacity, self.to_local());
             buffer.set_len(len);
-            String::from_utf8_unchecked(buffer)
         }
     }
 
@@ -664,6 +843,85 @@ impl JsString {
         }
     }
 
+    /// Tries to create a new `JsString` value from a Rust string, truncated to at most
+    /// `max_bytes` bytes of UTF-8 before appending `suffix`.
+    ///
+    /// If `val` fits within `max_bytes`, the full string is returned unchanged.
+    /// Otherwise, `val` is cut at the largest character boundary that does not exceed
+    /// `max_bytes` and `suffix` is appended to the result. Truncation never splits a
+    /// multi-byte character. Since the suffix is not counted against `max_bytes`, the
+    /// result may be up to `max_bytes + suffix.len()` bytes long.
+    ///
+    /// Returns `Err(StringOverflow)` if the resulting string is longer than the maximum
+    /// string size allowed by the JavaScript engine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn string_try_new_truncated(mut cx: FunctionContext) -> JsResult<JsString> {
+    /// // The emoji is 4 bytes long and does not fit in the first 8 bytes
+    /// let s = JsString::try_new_truncated(&mut cx, "hello 🥹", 8, "[…]").or_throw(&mut cx)?;
+    /// assert_eq!(s.value(&mut cx), "hello […]");
+    /// # Ok(s)
+    /// # }
+    /// ```
+    pub fn try_new_truncated<'a, C: Context<'a>, S: AsRef<str>>(
+        cx: &mut C,
+        val: S,
+        max_bytes: usize,
+        suffix: &str,
+    ) -> StringResult<'a> {
+        let val = val.as_ref();
+
+        if val.len() <= max_bytes {
+            return JsString::try_new(cx, val);
+        }
+
+        let mut end = max_bytes;
+
+        while !val.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        JsString::try_new(cx, [&val[..end], suffix].concat())
+    }
+
+    /// Tries to create a new `JsString` value from UTF-16 code units by copying them.
+    ///
+    /// This is the inverse of [`JsString::to_utf16`] and avoids a round-trip through a
+    /// Rust [`String`] when the data is already UTF-16, e.g. when it comes from
+    /// Windows APIs.
+    ///
+    /// Returns `Err(StringOverflow)` if the string is longer than the maximum string size
+    /// allowed by the JavaScript engine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn string_from_utf16(mut cx: FunctionContext) -> JsResult<JsString> {
+    /// let utf16: Vec<u16> = "hello 🥹".encode_utf16().collect();
+    /// let s = JsString::from_utf16(&mut cx, &utf16).or_throw(&mut cx)?;
+    /// assert_eq!(s.value(&mut cx), "hello 🥹");
+    /// # Ok(s)
+    /// # }
+    /// ```
+    ///
+    /// **See also:** [`Context::string_utf16`]
+    pub fn from_utf16<'a, C: Context<'a>>(cx: &mut C, val: &[u16]) -> StringResult<'a> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            let env = cx.env().to_raw();
+
+            if sys::string::new_utf16(&mut local, env, val.as_ptr(), val.len()) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(val.len()))
+            }
+        }
+    }
+
     pub(crate) fn new_internal<'a>(env: Env, val: &str) -> Option<Handle<'a, JsString>> {
         let (ptr, len) = if let Some(small) = Utf8::from(val).into_small() {
             small.lower()
@@ -704,6 +962,15 @@ impl JsString {
 pub struct JsNumber(raw::Local);
 
 impl JsNumber {
+    /// The largest integer `n` such that `n` and `n + 1` are both exactly representable,
+    /// equivalent to the JavaScript
+    /// [`Number.MAX_SAFE_INTEGER`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MAX_SAFE_INTEGER).
+    pub const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+    /// The smallest safe integer, equivalent to the JavaScript
+    /// [`Number.MIN_SAFE_INTEGER`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/MIN_SAFE_INTEGER).
+    pub const MIN_SAFE_INTEGER: f64 = -9_007_199_254_740_991.0;
+
     /// Creates a new number with value `x`.
     ///
     /// **See also:** [`Context::number`]
@@ -724,6 +991,40 @@ impl JsNumber {
         let env = cx.env().to_raw();
         unsafe { sys::primitive::number_value(env, self.to_local()) }
     }
+
+    /// Indicates whether this number is an integer that can be represented exactly,
+    /// equivalent to the JavaScript expression
+    /// [`Number.isSafeInteger(this)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isSafeInteger).
+    pub fn is_safe_integer<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
+        let v = self.value(cx);
+
+        v.trunc() == v && (Self::MIN_SAFE_INTEGER..=Self::MAX_SAFE_INTEGER).contains(&v)
+    }
+
+    /// Returns the value of this number as an `i64`, or `None` if it is not a
+    /// [safe integer](JsNumber::is_safe_integer).
+    ///
+    /// Values such as `2 ** 53 + 1` have already lost precision by the time they reach
+    /// Rust, so they are rejected rather than silently rounded.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn next_id(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let id = cx.argument::<JsNumber>(0)?;
+    ///
+    ///     match id.as_i64_checked(&mut cx) {
+    ///         Some(id) => Ok(cx.number((id + 1) as f64)),
+    ///         None => cx.throw_range_error("id must be a safe integer"),
+    ///     }
+    /// }
+    /// ```
+    pub fn as_i64_checked<'a, C: Context<'a>>(&self, cx: &mut C) -> Option<i64> {
+        if self.is_safe_integer(cx) {
+            Some(self.value(cx) as i64)
+        } else {
+            None
+        }
+    }
 }
 
 impl Value for JsNumber {}
@@ -881,6 +1182,51 @@ impl JsArray {
         }
     }
 
+    /// Constructs a new array containing the elements of `items`, in order.
+    ///
+    /// Every index from `0` to `items.len()` is populated, so the resulting array
+    /// is dense (i.e., not sparse).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn foo(mut cx: FunctionContext) -> JsResult<JsArray> {
+    /// let items = [cx.number(1), cx.number(2), cx.number(3)];
+    /// let a = JsArray::from_slice(&mut cx, &items)?;
+    /// # Ok(a)
+    /// # }
+    /// ```
+    pub fn from_slice<'a, 'b, C: Context<'a>, V: Value>(
+        cx: &mut C,
+        items: &[Handle<'b, V>],
+    ) -> JsResult<'a, JsArray> {
+        if u32::try_from(items.len()).is_err() {
+            return cx.throw_range_error("array length out of range");
+        }
+
+        let env = cx.env();
+        let array = JsArray::new_internal(env, items.len());
+
+        for (i, item) in items.iter().enumerate() {
+            let mut ok = false;
+
+            unsafe {
+                if !sys::object::set_index(
+                    &mut ok,
+                    env.to_raw(),
+                    array.to_local(),
+                    i as u32,
+                    item.to_local(),
+                ) {
+                    return Err(Throw::new());
+                }
+            }
+        }
+
+        Ok(array)
+    }
+
     /// Copies the array contents into a new [`Vec`] by iterating through all indices
     /// from 0 to `self.len()`.
     ///
@@ -900,6 +1246,44 @@ impl JsArray {
         }
     }
 
+    /// Returns an iterator that lazily reads the array elements, in order.
+    ///
+    /// Like [`JsArray::to_vec`], the length is dynamically checked on each iteration
+    /// in case the array is modified during the computation. Unlike `to_vec`, elements
+    /// are only read as they are consumed, so short-circuiting consumers such as
+    /// [`Iterator::find`] avoid reading the rest of the array.
+    ///
+    /// If reading an element throws an exception, the iterator yields the `Err` and
+    /// then ends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn foo(mut cx: FunctionContext) -> JsResult<JsValue> {
+    /// let a: Handle<JsArray> = cx.argument(0)?;
+    /// let mut iter = a.iter(&mut cx);
+    ///
+    /// // Find the first element that is a string
+    /// while let Some(item) = iter.next() {
+    ///     let item = item?;
+    ///
+    ///     if item.is_a::<JsString, _>(iter.cx()) {
+    ///         return Ok(item);
+    ///     }
+    /// }
+    /// # Ok(cx.undefined().upcast())
+    /// # }
+    /// ```
+    pub fn iter<'a, 'cx: 'a, C: Context<'cx>>(&self, cx: &'a mut C) -> ArrayIter<'a, 'cx, C> {
+        ArrayIter {
+            cx,
+            array: Handle::new_internal(JsArray(self.to_local())),
+            index: 0,
+            done: false,
+        }
+    }
+
     fn len_inner(&self, env: Env) -> u32 {
         unsafe { sys::array::len(env.to_raw(), self.to_local()) }
     }
@@ -916,8 +1300,105 @@ impl JsArray {
     pub fn is_empty<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
         self.len(cx) == 0
     }
+
+    /// Appends `value` to the end of the array, returning the new length.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.push(value)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/push).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn foo(mut cx: FunctionContext) -> JsResult<JsArray> {
+    /// let a = cx.empty_array();
+    ///
+    /// for word in ["neon", "rust"] {
+    ///     a.push(&mut cx, word)?;
+    /// }
+    /// # Ok(a)
+    /// # }
+    /// ```
+    pub fn push<'a, C, V>(&self, cx: &mut C, value: V) -> NeonResult<u32>
+    where
+        C: Context<'a>,
+        V: TryIntoJs<'a>,
+    {
+        let cx = cx.cx_mut();
+        let len: f64 = array_push(cx)?
+            .bind(cx)
+            .this(self.as_handle())?
+            .arg(value)?
+            .call()?;
+
+        Ok(len as u32)
+    }
+
+    /// Removes the last element of the array and returns it, or returns `undefined`
+    /// if the array is empty.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.pop()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/pop).
+    pub fn pop<'a, C: Context<'a>>(&self, cx: &mut C) -> JsResult<'a, JsValue> {
+        let cx = cx.cx_mut();
+
+        array_pop(cx)?.bind(cx).this(self.as_handle())?.call()
+    }
+
+    /// Removes `delete_count` elements starting at index `start` and inserts `items` in
+    /// their place, returning a new array of the removed elements.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.splice(start, deleteCount, ...items)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/splice).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn foo(mut cx: FunctionContext) -> JsResult<JsArray> {
+    /// let a: Handle<JsArray> = cx.argument(0)?;
+    ///
+    /// // Replace the first two elements with a single zero
+    /// let zero = cx.number(0);
+    /// let removed = a.splice(&mut cx, 0, 2, &[zero])?;
+    /// # Ok(removed)
+    /// # }
+    /// ```
+    pub fn splice<'a, 'b, C: Context<'a>, V: Value>(
+        &self,
+        cx: &mut C,
+        start: u32,
+        delete_count: u32,
+        items: &[Handle<'b, V>],
+    ) -> JsResult<'a, JsArray> {
+        let cx = cx.cx_mut();
+        let splice = array_splice(cx)?;
+        let mut bind = splice.bind(cx);
+
+        bind.this(self.as_handle())?.arg(start)?.arg(delete_count)?;
+
+        for item in items {
+            bind.arg(Handle::new_internal(JsValue(item.to_local())))?;
+        }
+
+        bind.call()
+    }
+
+    fn as_handle<'a>(&self) -> Handle<'a, JsArray> {
+        Handle::new_internal(JsArray(self.to_local()))
+    }
 }
 
+fn global_array_method<'cx>(cx: &mut Cx<'cx>, name: &str) -> JsResult<'cx, JsFunction> {
+    cx.global::<JsFunction>("Array")?
+        .get::<JsObject, _, _>(cx, "prototype")?
+        .get(cx, name)
+}
+
+global_function!(array_push, |cx| global_array_method(cx, "push"));
+global_function!(array_pop, |cx| global_array_method(cx, "pop"));
+global_function!(array_splice, |cx| global_array_method(cx, "splice"));
+
 impl Value for JsArray {}
 
 unsafe impl TransparentNoCopyWrapper for JsArray {
@@ -948,6 +1429,49 @@ impl ValueInternal for JsArray {
 
 impl Object for JsArray {}
 
+/// An iterator over the elements of a [`JsArray`].
+///
+/// This type is created by the [`JsArray::iter`] method.
+pub struct ArrayIter<'a, 'cx: 'a, C: Context<'cx>> {
+    cx: &'a mut C,
+    array: Handle<'cx, JsArray>,
+    index: u32,
+    done: bool,
+}
+
+impl<'a, 'cx: 'a, C: Context<'cx>> ArrayIter<'a, 'cx, C> {
+    /// Returns the context borrowed by this iterator, for use between iterations.
+    pub fn cx(&mut self) -> &mut C {
+        self.cx
+    }
+}
+
+impl<'a, 'cx: 'a, C: Context<'cx>> Iterator for ArrayIter<'a, 'cx, C> {
+    type Item = NeonResult<Handle<'cx, JsValue>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let env = self.cx.env();
+
+        // Since getting a property can trigger arbitrary code,
+        // we have to re-check the length on every iteration.
+        if self.done || self.index >= self.array.len_inner(env) {
+            self.done = true;
+            return None;
+        }
+
+        let item = build(env, |out| unsafe {
+            sys::object::get_index(out, env.to_raw(), self.array.to_local(), self.index)
+        });
+
+        self.index += 1;
+        self.done = item.is_err();
+
+        Some(item)
+    }
+}
+
+impl<'a, 'cx: 'a, C: Context<'cx>> std::iter::FusedIterator for ArrayIter<'a, 'cx, C> {}
+
 /// The type of JavaScript
 /// [`Function`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function)
 /// objects.
@@ -1056,6 +1580,48 @@ impl JsFunction {
         Self::new_internal(cx, f, name)
     }
 
+    #[cfg(feature = "napi-5")]
+    /// Returns a new `JsFunction` implemented by the stateful closure `f`.
+    ///
+    /// Unlike [`JsFunction::new`], `f` may be [`FnMut`]. It is stored in a
+    /// [`RefCell`](std::cell::RefCell) and mutably borrowed for the duration of each
+    /// call, which avoids wrapping captured state in interior mutability by hand.
+    ///
+    /// Since only one mutable borrow can exist at a time, if `f` calls back into
+    /// JavaScript and that code calls the same function again, the nested call
+    /// throws an `Error` instead of running `f`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn make_counter(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    ///     let mut count = 0;
+    ///
+    ///     JsFunction::new_mut(&mut cx, move |mut cx| {
+    ///         count += 1;
+    ///
+    ///         Ok(cx.number(count))
+    ///     })
+    /// }
+    /// ```
+    pub fn new_mut<'a, C, F, V>(cx: &mut C, f: F) -> JsResult<'a, JsFunction>
+    where
+        C: Context<'a>,
+        F: FnMut(FunctionContext) -> JsResult<V> + 'static,
+        V: Value,
+    {
+        let name = any::type_name::<F>();
+        let f = std::cell::RefCell::new(f);
+
+        Self::new_internal(
+            cx,
+            move |mut cx| match f.try_borrow_mut() {
+                Ok(mut f) => f(cx).map(|v| v.upcast::<JsValue>()),
+                Err(_) => cx.throw_error("function cannot be called reentrantly"),
+            },
+            name,
+        )
+    }
+
     #[cfg(not(feature = "napi-5"))]
     /// Returns a new `JsFunction` implemented by `f` with specified name
     pub fn with_name<'a, C, U>(
@@ -1081,6 +1647,67 @@ impl JsFunction {
         Self::new_internal(cx, f, name)
     }
 
+    #[cfg(not(feature = "napi-5"))]
+    /// Returns a new `JsFunction` implemented by `f` with specified name and
+    /// [`length`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/length).
+    ///
+    /// The `length` only affects the number of parameters reported to JavaScript;
+    /// `f` may still be called with any number of arguments.
+    pub fn with_arity<'a, C, U>(
+        cx: &mut C,
+        name: &str,
+        length: usize,
+        f: fn(FunctionContext) -> JsResult<U>,
+    ) -> JsResult<'a, JsFunction>
+    where
+        C: Context<'a>,
+        U: Value,
+    {
+        let fun = Self::new_internal(cx, f, name)?;
+
+        fun.redefine_property(cx.cx_mut(), "length", length as f64)?;
+
+        Ok(fun)
+    }
+
+    #[cfg(feature = "napi-5")]
+    /// Returns a new `JsFunction` implemented by `f` with specified name and
+    /// [`length`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/length).
+    ///
+    /// The `length` only affects the number of parameters reported to JavaScript;
+    /// `f` may still be called with any number of arguments.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn add(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let (a, b): (f64, f64) = cx.args()?;
+    ///
+    ///     Ok(cx.number(a + b))
+    /// }
+    ///
+    /// fn make_add(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    ///     // `add.length === 2`
+    ///     JsFunction::with_arity(&mut cx, "add", 2, add)
+    /// }
+    /// ```
+    pub fn with_arity<'a, C, F, V>(
+        cx: &mut C,
+        name: &str,
+        length: usize,
+        f: F,
+    ) -> JsResult<'a, JsFunction>
+    where
+        C: Context<'a>,
+        F: Fn(FunctionContext) -> JsResult<V> + 'static,
+        V: Value,
+    {
+        let fun = Self::new_internal(cx, f, name)?;
+
+        fun.redefine_property(cx.cx_mut(), "length", length as f64)?;
+
+        Ok(fun)
+    }
+
     fn new_internal<'a, C, F, V>(cx: &mut C, f: F, name: &str) -> JsResult<'a, JsFunction>
     where
         C: Context<'a>,
@@ -1136,6 +1763,38 @@ impl JsFunction {
         unsafe { self.try_call(cx, this, args) }
     }
 
+    /// Calls this function with the elements of the array `args` as its arguments,
+    /// equivalent to the JavaScript expression
+    /// [`this.apply(thisArg, args)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/apply).
+    ///
+    /// The arguments are spread by the JavaScript engine, without first copying them into
+    /// a Rust `Vec`. Throws a `RangeError` if `args` has more elements than the engine
+    /// allows as arguments to a single call.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn max_of(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let numbers: Handle<JsArray> = cx.argument(0)?;
+    ///     let math: Handle<JsObject> = cx.global("Math")?;
+    ///     let max: Handle<JsFunction> = math.get(&mut cx, "max")?;
+    ///
+    ///     max.apply(&mut cx, math, numbers)
+    /// }
+    /// ```
+    pub fn apply<'a, 'b, C: Context<'a>, T: Value>(
+        &self,
+        cx: &mut C,
+        this: Handle<'b, T>,
+        args: Handle<'b, JsArray>,
+    ) -> JsResult<'a, JsValue> {
+        let cx = cx.cx_mut();
+        let reflect: Handle<JsObject> = cx.global("Reflect")?;
+        let apply: Handle<JsFunction> = reflect.get(cx, "apply")?;
+        let callee = self.as_value(cx);
+
+        apply.call(cx, reflect, [callee, this.upcast(), args.upcast()])
+    }
+
     /// Calls this function for side effect, discarding its result.
     ///
     /// **See also:** [`JsFunction::bind`].
@@ -1198,6 +1857,58 @@ impl JsFunction {
     }
 }
 
+impl JsFunction {
+    /// Returns the name of this function, equivalent to the JavaScript expression
+    /// [`this.name`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/name).
+    pub fn name<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<String> {
+        self.prop(cx.cx_mut(), "name").get()
+    }
+
+    /// Changes the name of this function, as displayed in stack traces and by
+    /// [`JsFunction::name`].
+    ///
+    /// The `name` property of a function is not writable, so it is redefined with
+    /// the equivalent of the JavaScript expression
+    /// `Object.defineProperty(this, "name", { value: name, configurable: true })`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn make_greeter(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    ///     let f = JsFunction::new(&mut cx, |mut cx| Ok(cx.string("hello")))?;
+    ///
+    ///     f.set_name(&mut cx, "greet")?;
+    ///
+    ///     Ok(f)
+    /// }
+    /// ```
+    pub fn set_name<'a, C: Context<'a>>(&self, cx: &mut C, name: &str) -> NeonResult<()> {
+        self.redefine_property(cx.cx_mut(), "name", name)
+    }
+
+    // Redefines a non-writable, configurable property such as `name` or `length`
+    fn redefine_property<'cx, V>(&self, cx: &mut Cx<'cx>, key: &str, value: V) -> NeonResult<()>
+    where
+        V: TryIntoJs<'cx>,
+    {
+        let this = self.as_value(cx);
+        let object: Handle<JsFunction> = cx.global("Object")?;
+        let descriptor = cx.empty_object();
+
+        descriptor
+            .prop(cx, "value")
+            .set(value)?
+            .prop("configurable")
+            .set(true)?;
+
+        object
+            .method(cx, "defineProperty")?
+            .arg(this)?
+            .arg(key)?
+            .arg(descriptor)?
+            .exec()
+    }
+}
+
 impl JsFunction {
     /// Create a [`CallOptions`](function::CallOptions) for calling this function.
     #[deprecated(since = "TBD", note = "use `JsFunction::bind` instead")]
@@ -1,5 +1,8 @@
 use std::ptr;
 
+#[cfg(feature = "napi-4")]
+use std::{error::Error, fmt};
+
 use crate::{
     context::{
         internal::{ContextInternal, Env},
@@ -15,7 +18,7 @@ use crate::{
 #[cfg(feature = "napi-4")]
 use crate::{
     context::Cx,
-    event::{Channel, JoinHandle, SendError},
+    event::{Channel, JoinHandle},
 };
 
 #[cfg(feature = "napi-6")]
@@ -168,6 +171,54 @@ impl JsPromise {
         promise
     }
 
+    #[cfg(all(feature = "napi-6", feature = "futures"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-6", feature = "futures"))))]
+    /// Creates a promise that is settled with the output of a Rust
+    /// [`Future`](std::future::Future).
+    ///
+    /// The future is spawned on the executor registered with
+    /// [`set_global_executor`](crate::set_global_executor). Once it completes, `settle` is
+    /// called on the JavaScript thread with its output. The promise resolves with the value
+    /// returned by `settle`, or rejects if `settle` throws.
+    ///
+    /// Throws an `Error` if a global executor has not been registered.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn delayed_sum(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let a = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    ///     let b = cx.argument::<JsNumber>(1)?.value(&mut cx);
+    ///
+    ///     JsPromise::from_future(&mut cx, async move { a + b }, |mut cx, sum| {
+    ///         Ok(cx.number(sum))
+    ///     })
+    /// }
+    /// ```
+    pub fn from_future<'a, C, F, S, V>(cx: &mut C, fut: F, settle: S) -> JsResult<'a, JsPromise>
+    where
+        C: Context<'a>,
+        F: Future + Send + 'static,
+        F::Output: Send,
+        S: FnOnce(Cx, F::Output) -> JsResult<V> + Send + 'static,
+        V: Value,
+    {
+        let cx = cx.cx_mut();
+        let rt = match crate::executor::global_executor(cx) {
+            Some(rt) => rt,
+            None => return cx.throw_error("must initialize with neon::set_global_executor"),
+        };
+
+        let ch = cx.channel();
+        let (d, promise) = cx.promise();
+
+        rt.spawn(Box::pin(async move {
+            let res = fut.await;
+            let _ = d.try_settle_with(&ch, move |cx| settle(cx, res));
+        }));
+
+        Ok(promise)
+    }
+
     #[cfg(all(feature = "napi-5", feature = "futures"))]
     #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-5", feature = "futures"))))]
     /// Creates a [`Future`](std::future::Future) that can be awaited to receive the result of a
@@ -176,6 +227,24 @@ impl JsPromise {
     /// A callback must be provided that maps a `Result` representing the resolution or rejection of
     /// the `Promise` and returns a value as the `Future` output.
     ///
+    /// The rejection reason is passed to the callback as `Err(value)`. Throwing from the callback
+    /// causes the `Future` to complete with a [`JoinError`](crate::event::JoinError) that does not
+    /// include the reason, so callers that need it should convert it to Rust data instead:
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn example(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// let promise = cx.argument::<JsPromise>(0)?;
+    /// let future = promise.to_future(&mut cx, |mut cx, result| match result {
+    ///     Ok(value) => Ok(Ok(value.to_string(&mut cx)?.value(&mut cx))),
+    ///     // Preserve the rejection reason as the error
+    ///     Err(reason) => Ok(Err(reason.to_string(&mut cx)?.value(&mut cx))),
+    /// })?;
+    /// # let _: neon::types::JsFuture<Result<String, String>> = future;
+    /// # Ok(cx.undefined())
+    /// # }
+    /// ```
+    ///
     /// _Note_: Unlike `Future`, `Promise` are eagerly evaluated and so are `JsFuture`.
     pub fn to_future<'a, O, C, F>(&self, cx: &mut C, f: F) -> NeonResult<JsFuture<O>>
     where
@@ -320,21 +389,53 @@ impl Deferred {
     ///
     /// Usage is identical to [`Deferred::settle_with`].
     ///
-    /// Returns a [`SendError`][crate::event::SendError] if sending the closure to the main JavaScript thread fails.
+    /// Returns a [`DeferredError`] if sending the closure to the main JavaScript thread fails.
+    /// Use [`DeferredError::kind`] to distinguish a JavaScript thread that has shut down
+    /// from other failures. In either case the closure is dropped without being called and
+    /// the promise is never settled.
     /// See [`Channel::try_send`][crate::event::Channel::try_send] for more details.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use neon::types::DeferredErrorKind;
+    /// # fn example(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    /// let channel = cx.channel();
+    /// let (deferred, promise) = cx.promise();
+    ///
+    /// std::thread::spawn(move || {
+    ///     let result = deferred.try_settle_with(&channel, move |mut cx| Ok(cx.number(42)));
+    ///
+    ///     if let Err(err) = result {
+    ///         if err.kind() == DeferredErrorKind::Closed {
+    ///             eprintln!("JavaScript thread exited before the result was sent");
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// # Ok(promise)
+    /// # }
+    /// ```
     pub fn try_settle_with<V, F>(
         self,
         channel: &Channel,
         complete: F,
-    ) -> Result<JoinHandle<()>, SendError>
+    ) -> Result<JoinHandle<()>, DeferredError>
     where
         V: Value,
         F: FnOnce(Cx) -> JsResult<V> + Send + 'static,
     {
-        channel.try_send(move |cx| {
-            self.try_catch_settle(cx, complete);
-            Ok(())
-        })
+        channel
+            .schedule(move |cx| {
+                self.try_catch_settle(cx, complete);
+                Ok(())
+            })
+            .map_err(|err| {
+                DeferredError(if err.is_closing() {
+                    DeferredErrorKind::Closed
+                } else {
+                    DeferredErrorKind::SendFailed
+                })
+            })
     }
 
     #[cfg(feature = "napi-4")]
@@ -386,6 +487,53 @@ impl Deferred {
     }
 }
 
+#[cfg(feature = "napi-4")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
+/// An error produced when a [`Deferred`] could not be settled with
+/// [`Deferred::try_settle_with`].
+#[derive(Debug)]
+pub struct DeferredError(DeferredErrorKind);
+
+#[cfg(feature = "napi-4")]
+impl DeferredError {
+    pub fn kind(&self) -> DeferredErrorKind {
+        self.0
+    }
+}
+
+#[cfg(feature = "napi-4")]
+impl fmt::Display for DeferredError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.0.as_str())
+    }
+}
+
+#[cfg(feature = "napi-4")]
+impl Error for DeferredError {}
+
+#[cfg(feature = "napi-4")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
+/// The error kinds corresponding to `DeferredError`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DeferredErrorKind {
+    /// The JavaScript thread has shut down or is shutting down, for example
+    /// because of a call to `process.exit()`.
+    Closed,
+    /// The closure could not be scheduled on the JavaScript thread for
+    /// another reason.
+    SendFailed,
+}
+
+#[cfg(feature = "napi-4")]
+impl DeferredErrorKind {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            DeferredErrorKind::Closed => "JavaScript thread has shut down",
+            DeferredErrorKind::SendFailed => "Failed to send closure to JavaScript thread",
+        }
+    }
+}
+
 #[repr(transparent)]
 pub(crate) struct NodeApiDeferred(sys::Deferred);
 
@@ -0,0 +1,57 @@
+use crate::{
+    context::Context,
+    handle::Handle,
+    result::JsResult,
+    types::{JsFunction, JsObject},
+};
+
+/// Constructs JavaScript
+/// [`Proxy`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy)
+/// objects.
+///
+/// A proxy wraps a _target_ object and intercepts operations on it with the _traps_
+/// defined on a _handler_ object. Traps are ordinary JavaScript functions, so they may
+/// also be defined in Rust with [`JsFunction::new`].
+///
+/// Unlike the other types in this module, `JsProxy` is not a value type and cannot be
+/// instantiated. By design of the JavaScript specification, a proxy is indistinguishable
+/// from its target, so there is no way to check whether a value is a proxy. Proxies are
+/// instead represented as a [`JsObject`], and a proxy of a function or an array will
+/// also be recognized as that type by [`Handle::downcast`].
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsProxy;
+///
+/// fn read_only(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let target: Handle<JsObject> = cx.argument(0)?;
+///     let handler = cx.empty_object();
+///     let set = JsFunction::new(&mut cx, |mut cx| {
+///         cx.throw_type_error::<_, Handle<JsValue>>("object is read-only")
+///     })?;
+///
+///     handler.prop(&mut cx, "set").set(set)?;
+///
+///     JsProxy::new(&mut cx, target, handler)
+/// }
+/// ```
+#[derive(Debug)]
+pub enum JsProxy {}
+
+impl JsProxy {
+    /// Creates a new proxy for `target`, equivalent to the JavaScript expression
+    /// `new Proxy(target, handler)`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<'a, C: Context<'a>>(
+        cx: &mut C,
+        target: Handle<'a, JsObject>,
+        handler: Handle<'a, JsObject>,
+    ) -> JsResult<'a, JsObject> {
+        let cx = cx.cx_mut();
+        let proxy: Handle<JsFunction> = cx.global("Proxy")?;
+
+        proxy.bind(cx).arg(target)?.arg(handler)?.construct()
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    context::{internal::Env, Context, Cx},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::raw,
+    types::{brand, private::ValueInternal, JsArray, JsFunction, JsString, Value},
+};
+
+/// The type of JavaScript
+/// [`RegExp`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp)
+/// objects.
+///
+/// A `JsRegExp` makes it possible to match strings against patterns using the
+/// JavaScript engine's own regular expression implementation.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsRegExp;
+///
+/// fn is_identifier(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+///     let input = cx.argument::<JsString>(0)?;
+///     let re = JsRegExp::new(&mut cx, "^[A-Za-z_][A-Za-z0-9_]*$", "")?;
+///     let matched = re.test(&mut cx, input)?;
+///
+///     Ok(cx.boolean(matched))
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsRegExp(raw::Local);
+
+impl JsRegExp {
+    /// Creates a new regular expression, equivalent to the JavaScript expression
+    /// `new RegExp(pattern, flags)`.
+    ///
+    /// Throws a `SyntaxError` if the pattern or flags are invalid.
+    pub fn new<'a, C: Context<'a>>(
+        cx: &mut C,
+        pattern: &str,
+        flags: &str,
+    ) -> JsResult<'a, JsRegExp> {
+        let cx = cx.cx_mut();
+        let regexp: Handle<JsFunction> = cx.global("RegExp")?;
+
+        regexp.bind(cx).arg(pattern)?.arg(flags)?.construct()
+    }
+
+    /// Returns the text of the pattern, equivalent to the JavaScript expression
+    /// [`this.source`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/source).
+    pub fn source<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<String> {
+        self.prop(cx.cx_mut(), "source").get()
+    }
+
+    /// Returns the flags of the regular expression, equivalent to the JavaScript expression
+    /// [`this.flags`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/flags).
+    pub fn flags<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<String> {
+        self.prop(cx.cx_mut(), "flags").get()
+    }
+
+    /// Indicates whether the regular expression matches `input`.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.test(input)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/test).
+    /// Note that for regular expressions with the global or sticky flag, this advances
+    /// [`lastIndex`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/lastIndex).
+    pub fn test<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        input: Handle<'a, JsString>,
+    ) -> NeonResult<bool> {
+        self.method(cx.cx_mut(), "test")?.arg(input)?.call()
+    }
+
+    /// Searches `input` for a match, returning the match array or `None` if there is
+    /// no match.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.exec(input)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/exec).
+    pub fn exec<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        input: Handle<'a, JsString>,
+    ) -> NeonResult<Option<Handle<'a, JsArray>>> {
+        self.method(cx.cx_mut(), "exec")?.arg(input)?.call()
+    }
+}
+
+impl Value for JsRegExp {}
+
+impl Object for JsRegExp {}
+
+unsafe impl TransparentNoCopyWrapper for JsRegExp {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsRegExp {
+    fn name() -> &'static str {
+        "RegExp"
+    }
+
+    fn is_typeof<Other: Value>(cx: &mut Cx, other: &Other) -> bool {
+        brand::is_regexp(cx, other.to_local())
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsRegExp(h)
+    }
+}
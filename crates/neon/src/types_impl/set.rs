@@ -0,0 +1,203 @@
+use crate::{
+    context::{internal::Env, Context, Cx},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::raw,
+    types::{
+        brand, extract::TryIntoJs, private::ValueInternal, JsFunction, JsObject, JsValue, Value,
+    },
+};
+
+/// The type of JavaScript
+/// [`Set`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set)
+/// objects.
+///
+/// A `JsSet` holds unique JavaScript values and remembers their insertion order.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsSet;
+///
+/// fn unique_words(mut cx: FunctionContext) -> JsResult<JsSet> {
+///     let text = cx.argument::<JsString>(0)?.value(&mut cx);
+///     let set = JsSet::new(&mut cx)?;
+///
+///     for word in text.split_whitespace() {
+///         set.add(&mut cx, word)?;
+///     }
+///
+///     Ok(set)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsSet(raw::Local);
+
+impl JsSet {
+    /// Creates a new empty `Set`, equivalent to the JavaScript expression `new Set()`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSet> {
+        let cx = cx.cx_mut();
+        let set: Handle<JsFunction> = cx.global("Set")?;
+
+        set.bind(cx).construct()
+    }
+
+    /// Adds `value` to the set if it is not already present.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.add(value)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/add).
+    pub fn add<'a, C, V>(&self, cx: &mut C, value: V) -> NeonResult<()>
+    where
+        C: Context<'a>,
+        V: TryIntoJs<'a>,
+    {
+        self.method(cx.cx_mut(), "add")?.arg(value)?.exec()
+    }
+
+    /// Indicates whether the set contains `value`.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.has(value)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/has).
+    pub fn has<'a, C, V>(&self, cx: &mut C, value: V) -> NeonResult<bool>
+    where
+        C: Context<'a>,
+        V: TryIntoJs<'a>,
+    {
+        self.method(cx.cx_mut(), "has")?.arg(value)?.call()
+    }
+
+    /// Removes `value` from the set, returning `true` if it was present.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.delete(value)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/delete).
+    pub fn delete<'a, C, V>(&self, cx: &mut C, value: V) -> NeonResult<bool>
+    where
+        C: Context<'a>,
+        V: TryIntoJs<'a>,
+    {
+        self.method(cx.cx_mut(), "delete")?.arg(value)?.call()
+    }
+
+    /// Returns the number of values in the set.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.size`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/size).
+    pub fn size<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<u32> {
+        let size: f64 = self.prop(cx.cx_mut(), "size").get()?;
+
+        Ok(size as u32)
+    }
+
+    /// Returns an iterator over the values of the set, in insertion order.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.values()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/values).
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use neon::types::JsSet;
+    /// # fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    /// let set: Handle<JsSet> = cx.argument(0)?;
+    /// let mut values = set.values(&mut cx)?;
+    /// let mut sum = 0.0;
+    ///
+    /// while let Some(value) = values.next() {
+    ///     let n: Handle<JsNumber> = value?.downcast_or_throw(values.cx())?;
+    ///
+    ///     sum += n.value(values.cx());
+    /// }
+    ///
+    /// Ok(cx.number(sum))
+    /// # }
+    /// ```
+    pub fn values<'a, 'cx: 'a, C: Context<'cx>>(
+        &self,
+        cx: &'a mut C,
+    ) -> NeonResult<SetValues<'a, 'cx, C>> {
+        let iterator = self.method(cx.cx_mut(), "values")?.call()?;
+
+        Ok(SetValues {
+            cx,
+            iterator,
+            done: false,
+        })
+    }
+}
+
+impl Value for JsSet {}
+
+impl Object for JsSet {}
+
+unsafe impl TransparentNoCopyWrapper for JsSet {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsSet {
+    fn name() -> &'static str {
+        "Set"
+    }
+
+    fn is_typeof<Other: Value>(cx: &mut Cx, other: &Other) -> bool {
+        brand::is_set(cx, other.to_local())
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsSet(h)
+    }
+}
+
+/// An iterator over the values of a [`JsSet`].
+///
+/// This type is created by the [`JsSet::values`] method.
+pub struct SetValues<'a, 'cx: 'a, C: Context<'cx>> {
+    cx: &'a mut C,
+    iterator: Handle<'cx, JsObject>,
+    done: bool,
+}
+
+impl<'a, 'cx: 'a, C: Context<'cx>> SetValues<'a, 'cx, C> {
+    /// Returns the context borrowed by this iterator, for use between iterations.
+    pub fn cx(&mut self) -> &mut C {
+        self.cx
+    }
+
+    fn next_value(&mut self) -> NeonResult<Option<Handle<'cx, JsValue>>> {
+        let cx = self.cx.cx_mut();
+        let result: Handle<JsObject> = self.iterator.method(cx, "next")?.call()?;
+
+        if result.prop(cx, "done").get()? {
+            return Ok(None);
+        }
+
+        result.prop(cx, "value").get().map(Some)
+    }
+}
+
+impl<'a, 'cx: 'a, C: Context<'cx>> Iterator for SetValues<'a, 'cx, C> {
+    type Item = NeonResult<Handle<'cx, JsValue>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let value = self.next_value().transpose();
+
+        self.done = !matches!(value, Some(Ok(_)));
+
+        value
+    }
+}
+
+impl<'a, 'cx: 'a, C: Context<'cx>> std::iter::FusedIterator for SetValues<'a, 'cx, C> {}
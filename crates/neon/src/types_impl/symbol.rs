@@ -0,0 +1,121 @@
+use std::ptr;
+
+use crate::{
+    context::{
+        internal::{ContextInternal, Env},
+        Context, Cx,
+    },
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw},
+    types::{build, private::ValueInternal, JsFunction, JsString, JsUndefined, JsValue, Value},
+};
+
+/// The type of JavaScript
+/// [`Symbol`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol)
+/// primitives.
+///
+/// Symbols are most often used as unique [property keys](crate::object::PropertyKey).
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsSymbol;
+///
+/// fn tag_object(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let obj = cx.empty_object();
+///
+///     // Properties keyed by a symbol cannot collide with string keys
+///     let tag = JsSymbol::new(&mut cx, Some("tag"));
+///     obj.prop(&mut cx, tag).set("hidden")?;
+///
+///     Ok(obj)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsSymbol(raw::Local);
+
+impl JsSymbol {
+    /// Creates a new unique symbol with an optional description, equivalent to the
+    /// JavaScript expression `Symbol(description)`.
+    ///
+    /// This method panics if the description is longer than the maximum string size
+    /// allowed by the JavaScript engine.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, description: Option<&str>) -> Handle<'a, JsSymbol> {
+        let description = match description {
+            Some(description) => JsString::new(cx, description).to_local(),
+            None => ptr::null_mut(),
+        };
+
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            sys::primitive::symbol(&mut local, cx.env().to_raw(), description);
+            Handle::new_internal(JsSymbol(local))
+        }
+    }
+
+    /// Gets the symbol registered for `key` in the global symbol registry, creating it
+    /// if it does not already exist. Equivalent to the JavaScript expression
+    /// [`Symbol.for(key)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/for).
+    ///
+    /// Unlike [`JsSymbol::new`], calling this method multiple times with the same
+    /// key produces the same symbol.
+    pub fn for_key<'a, C: Context<'a>>(cx: &mut C, key: &str) -> JsResult<'a, JsSymbol> {
+        let cx = cx.cx_mut();
+        let symbol: Handle<JsFunction> = cx.global("Symbol")?;
+
+        symbol.method(cx, "for")?.arg(key)?.call()
+    }
+
+    /// Returns the description of the symbol, or `None` if it was created without one.
+    ///
+    /// Equivalent to the JavaScript expression
+    /// [`this.description`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/description).
+    pub fn description<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<Option<String>> {
+        let description = "description";
+        let env = cx.env();
+        let (ptr, len) = (description.as_ptr(), description.len() as i32);
+        let value: Handle<JsValue> = build(env, |out| unsafe {
+            sys::object::get_string(env.to_raw(), out, self.to_local(), ptr, len)
+        })?;
+
+        if value.is_a::<JsUndefined, _>(cx) {
+            return Ok(None);
+        }
+
+        let value: Handle<JsString> = value.downcast_or_throw(cx)?;
+
+        Ok(Some(value.value(cx)))
+    }
+}
+
+impl Value for JsSymbol {}
+
+unsafe impl TransparentNoCopyWrapper for JsSymbol {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsSymbol {
+    fn name() -> &'static str {
+        "symbol"
+    }
+
+    fn is_typeof<Other: Value>(cx: &mut Cx, other: &Other) -> bool {
+        unsafe { sys::tag::is_symbol(cx.env().to_raw(), other.to_local()) }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsSymbol(h)
+    }
+}
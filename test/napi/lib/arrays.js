@@ -14,6 +14,53 @@ describe("JsArray", function () {
     assert.deepEqual(["hello node"], addon.return_js_array_with_string());
   });
 
+  it("return a dense JsArray built from a slice", function () {
+    const array = addon.return_js_array_from_slice();
+
+    assert.deepEqual([1, "two", true], array);
+    assert.ok(0 in array && 1 in array && 2 in array);
+  });
+
+  it("return an empty JsArray built from an empty slice", function () {
+    assert.deepEqual([], addon.return_empty_js_array_from_slice());
+  });
+
+  it("can lazily iterate a JsArray and stop early", function () {
+    assert.deepEqual(addon.find_first_string_in_js_array([1, "a", 2, "b"]), [
+      "a",
+      2,
+    ]);
+    assert.deepEqual(addon.find_first_string_in_js_array([1, 2]), [
+      undefined,
+      2,
+    ]);
+  });
+
+  it("re-checks the length of a JsArray on each iteration", function () {
+    const array = [1, 2, 3, 4];
+
+    Object.defineProperty(array, 0, {
+      get() {
+        array.length = 2;
+        return 1;
+      },
+    });
+
+    assert.strictEqual(addon.count_js_array_iter(array), 2);
+  });
+
+  it("stops iterating a JsArray when an element throws", function () {
+    const array = [1, 2, 3];
+
+    Object.defineProperty(array, 1, {
+      get() {
+        throw new Error("oops");
+      },
+    });
+
+    assert.throws(() => addon.count_js_array_iter(array), /oops/);
+  });
+
   it("can read from a JsArray", function () {
     assert.strictEqual(addon.read_js_array([1234]), 1234);
   });
@@ -21,4 +68,35 @@ describe("JsArray", function () {
   it("returns undefined when accessing outside JsArray bounds", function () {
     assert.strictEqual(addon.read_js_array([]), undefined);
   });
+
+  it("can push to and pop from a JsArray", function () {
+    const array = [1];
+
+    assert.strictEqual(addon.push_js_array(array, "two"), 2);
+    assert.deepEqual(array, [1, "two"]);
+    assert.strictEqual(addon.pop_js_array(array), "two");
+    assert.strictEqual(addon.pop_js_array(array), 1);
+    assert.strictEqual(addon.pop_js_array(array), undefined);
+    assert.deepEqual(array, []);
+  });
+
+  it("uses Array.prototype methods rather than own properties", function () {
+    const array = [1, 2];
+
+    array.push = () => {
+      throw new Error("should not be called");
+    };
+
+    assert.strictEqual(addon.push_js_array(array, 3), 3);
+    assert.deepEqual([...array], [1, 2, 3]);
+  });
+
+  it("can splice a JsArray", function () {
+    const array = [1, 2, 3, 4];
+
+    assert.deepEqual(addon.splice_js_array(array, 1, 2, "a", "b", "c"), [2, 3]);
+    assert.deepEqual(array, [1, "a", "b", "c", 4]);
+    assert.deepEqual(addon.splice_js_array(array, 10, 1), []);
+    assert.deepEqual(array, [1, "a", "b", "c", 4]);
+  });
 });
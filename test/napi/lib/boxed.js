@@ -72,4 +72,27 @@ describe("boxed", function () {
 
     assert.throws(() => addon.person_greet(unit), /failed to downcast/);
   });
+
+  it("should box a value with a finalizer closure", function () {
+    const boxed = addon.person_new_with_finalizer("World", () => {});
+
+    assert.strictEqual(addon.boxed_string_value(boxed), "World");
+    assert.throws(() => addon.person_greet(boxed), /failed to downcast/);
+  });
+
+  (global.gc ? it : it.skip)(
+    "should call the finalizer closure when garbage collected",
+    function (cb) {
+      // Run from an `IIFE` to ensure that the box is out of scope and eligible for
+      // garbage collection when `global.gc()` is executed.
+      (() => {
+        addon.person_new_with_finalizer("World", (msg) => {
+          assert.strictEqual(msg, "Goodbye, World!");
+          cb();
+        });
+      })();
+
+      global.gc();
+    }
+  );
 });
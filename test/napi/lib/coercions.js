@@ -7,4 +7,28 @@ describe("coercions", function () {
     assert.strictEqual(addon.to_string(new Map()), "[object Map]");
     assert.strictEqual(addon.to_string({ a: "b" }), "[object Object]");
   });
+
+  it("can coerce to number", function () {
+    assert.strictEqual(addon.coerce_to_number("42"), 42);
+    assert.strictEqual(addon.coerce_to_number(true), 1);
+    assert.strictEqual(addon.coerce_to_number(null), 0);
+    assert.isNaN(addon.coerce_to_number({}));
+    assert.strictEqual(addon.coerce_to_number({ valueOf: () => 7 }), 7);
+    assert.throws(() => addon.coerce_to_number(Symbol()), TypeError);
+  });
+
+  it("can coerce to string", function () {
+    assert.strictEqual(addon.coerce_to_string(42), "42");
+    assert.strictEqual(addon.coerce_to_string(undefined), "undefined");
+    assert.strictEqual(addon.coerce_to_string({ toString: () => "hi" }), "hi");
+    assert.throws(() => addon.coerce_to_string(Symbol()), TypeError);
+  });
+
+  it("can coerce to boolean", function () {
+    assert.strictEqual(addon.coerce_to_boolean(""), false);
+    assert.strictEqual(addon.coerce_to_boolean(0), false);
+    assert.strictEqual(addon.coerce_to_boolean(null), false);
+    assert.strictEqual(addon.coerce_to_boolean("0"), true);
+    assert.strictEqual(addon.coerce_to_boolean({}), true);
+  });
 });
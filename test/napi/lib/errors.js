@@ -34,8 +34,167 @@ describe("errors", function () {
     assert.throws(() => addon.throw_error(msg), msg);
   });
 
+  it("should be able to create an error with a code", function () {
+    const err = addon.new_error_with_code("ERR_OH_NO", "Oh, no!");
+
+    assert.instanceOf(err, Error);
+    assert.strictEqual(err.code, "ERR_OH_NO");
+    assert.strictEqual(err.message, "Oh, no!");
+  });
+
+  it("should be able to throw an error with a code", function () {
+    try {
+      addon.throw_error_with_code("ERR_OH_NO", "Oh, no!");
+      assert.fail("expected an error to be thrown");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.code, "ERR_OH_NO");
+      assert.strictEqual(err.message, "Oh, no!");
+    }
+  });
+
+  it("should be able to create an error with a cause", function () {
+    const cause = new TypeError("bad input");
+    const err = addon.new_error_with_cause("Oh, no!", cause);
+
+    assert.instanceOf(err, Error);
+    assert.strictEqual(err.message, "Oh, no!");
+    assert.strictEqual(err.cause, cause);
+  });
+
+  it("should classify errors by kind", function () {
+    class ValidationError extends TypeError {}
+
+    assert.strictEqual(addon.error_kind(new Error()), "Error");
+    assert.strictEqual(addon.error_kind(new TypeError()), "TypeError");
+    assert.strictEqual(addon.error_kind(new RangeError()), "RangeError");
+    assert.strictEqual(addon.error_kind(new SyntaxError()), "SyntaxError");
+    assert.strictEqual(addon.error_kind(new URIError()), "URIError");
+    assert.strictEqual(
+      addon.error_kind(new AggregateError([])),
+      "AggregateError"
+    );
+    assert.strictEqual(addon.error_kind(new ValidationError()), "TypeError");
+    assert.strictEqual(
+      addon.error_kind(addon.new_range_error("")),
+      "RangeError"
+    );
+  });
+
+  it("should check for type and range errors", function () {
+    assert.deepEqual(addon.is_type_or_range_error(new TypeError()), [
+      true,
+      false,
+    ]);
+    assert.deepEqual(addon.is_type_or_range_error(new RangeError()), [
+      false,
+      true,
+    ]);
+    assert.deepEqual(addon.is_type_or_range_error(new Error()), [false, false]);
+  });
+
+  it("should classify errors with the original constructors", function () {
+    const { TypeError: OriginalTypeError } = globalThis;
+
+    // Caches the original constructor
+    assert.strictEqual(addon.error_kind(new TypeError()), "TypeError");
+
+    try {
+      globalThis.TypeError = function TypeError() {};
+      assert.strictEqual(
+        addon.error_kind(new OriginalTypeError()),
+        "TypeError"
+      );
+    } finally {
+      globalThis.TypeError = OriginalTypeError;
+    }
+  });
+
+  it("should throw if classifying an error throws", function () {
+    const err = new Error("hasInstance");
+
+    Object.defineProperty(TypeError, Symbol.hasInstance, {
+      configurable: true,
+      value() {
+        throw err;
+      },
+    });
+
+    try {
+      assert.throws(() => addon.error_kind(new Error()), err);
+      assert.throws(() => addon.is_type_or_range_error(new Error()), err);
+    } finally {
+      delete TypeError[Symbol.hasInstance];
+    }
+  });
+
+  it("should capture the JavaScript stack of the caller", function () {
+    function outerStackCaller() {
+      return innerStackCaller();
+    }
+
+    function innerStackCaller() {
+      return addon.capture_stack();
+    }
+
+    const stack = outerStackCaller();
+    const frames = stack.split("\n");
+
+    assert.match(frames[0], /^\s+at innerStackCaller /);
+    assert.match(frames[1], /^\s+at outerStackCaller /);
+    assert.include(frames[0], "errors.js");
+  });
+
   it("should be able to stringify a downcast error", function () {
     let msg = addon.downcast_error();
     assert.strictEqual(msg, "failed to downcast string to number");
   });
+
+  it("should throw a system error", function () {
+    try {
+      addon.throw_system_error("config.json");
+      assert.fail("expected an error");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(
+        err.message,
+        "ENOENT: no such file or directory, open 'config.json'"
+      );
+      assert.strictEqual(err.code, "ENOENT");
+      assert.strictEqual(err.errno, -2);
+      assert.strictEqual(err.syscall, "open");
+      assert.strictEqual(err.path, "config.json");
+      assert.isFalse("dest" in err);
+    }
+  });
+
+  it("should build a system error with a destination", function () {
+    const err = addon.build_system_error();
+
+    assert.instanceOf(err, Error);
+    assert.strictEqual(
+      err.message,
+      "EXDEV: cross-device link not permitted, rename '/tmp/a' -> '/home/b'"
+    );
+    assert.strictEqual(err.code, "EXDEV");
+    assert.strictEqual(err.syscall, "rename");
+    assert.strictEqual(err.path, "/tmp/a");
+    assert.strictEqual(err.dest, "/home/b");
+    assert.isFalse("errno" in err);
+  });
+
+  it("should include the actual type when a downcast throws", function () {
+    assert.strictEqual(addon.downcast_or_throw_number(17), 17);
+    assert.throws(
+      () => addon.downcast_or_throw_number("hi"),
+      TypeError,
+      "failed to downcast any to number (found string)"
+    );
+    assert.throws(() => addon.downcast_or_throw_number(null), /found null/);
+    assert.throws(() => addon.downcast_or_throw_number({}), /found object/);
+    assert.throws(
+      () => addon.downcast_or_throw_number(() => {}),
+      /found function/
+    );
+  });
 });
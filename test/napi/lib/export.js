@@ -30,6 +30,15 @@ function functions() {
     assert.strictEqual(addon.renamedAdd(1, 2), 3);
   });
 
+  it("camel case names", () => {
+    assert.strictEqual(addon.getUserById(1), "user-1");
+    assert.strictEqual(addon.get_user_by_id, undefined);
+
+    // An explicit `name` is used as-is
+    assert.strictEqual(addon.fetch_user_by_id(2), "user-2");
+    assert.strictEqual(addon.fetchUserById, undefined);
+  });
+
   it("add - task", async () => {
     const p1 = addon.addTask(1, 2);
     const p2 = addon.renamedAddTask(1, 2);
@@ -76,6 +85,24 @@ function functions() {
     assert.throws(() => addon.failWithThrow(msg), expected);
   });
 
+  it("throws errors that implement Display", async () => {
+    assert.strictEqual(addon.parseInt("42"), 42);
+    assert.throws(() => addon.parseInt("forty-two"), {
+      name: "Error",
+      message: "invalid digit found in string",
+    });
+
+    assert.strictEqual(await addon.parseIntTask("42"), 42);
+
+    try {
+      await addon.parseIntTask("");
+      assert.fail("expected task to reject");
+    } catch (err) {
+      assert.strictEqual(err.name, "Error");
+      assert.strictEqual(err.message, "cannot parse integer from empty string");
+    }
+  });
+
   it("tasks are concurrent", async () => {
     const time = 500;
     const sleep = (ms) => new Promise((r) => setTimeout(r, ms));
@@ -36,6 +36,38 @@ describe("Extractors", () => {
     ]);
   });
 
+  it("Optional trailing arguments", () => {
+    assert.strictEqual(addon.extract_optional_trailing("Neon"), "Hello, Neon!");
+    assert.strictEqual(
+      addon.extract_optional_trailing("Neon", undefined),
+      "Hello, Neon!"
+    );
+    assert.strictEqual(
+      addon.extract_optional_trailing("Neon", "Welcome"),
+      "Welcome, Neon!"
+    );
+    assert.throws(() => addon.extract_optional_trailing(), TypeError);
+  });
+
+  it("Rest arguments", () => {
+    assert.strictEqual(addon.extract_rest_join(", "), "");
+    assert.strictEqual(addon.extract_rest_join(", ", "a"), "a");
+    assert.strictEqual(addon.extract_rest_join(", ", "a", "b", "c"), "a, b, c");
+    assert.throws(() => addon.extract_rest_join(", ", "a", 1), TypeError);
+
+    assert.strictEqual(addon.extractRestSum(), 0);
+    assert.strictEqual(addon.extractRestSum(1, 2, 3, 36), 42);
+  });
+
+  it("Arguments with this", () => {
+    const person = { setName: addon.extract_args_with_this };
+
+    person.setName("Ada", "Lovelace");
+    assert.strictEqual(person.name, "Ada Lovelace");
+
+    assert.throws(() => person.setName("Ada"), TypeError);
+  });
+
   it("Buffers", () => {
     const test = (TypedArray) => {
       const buf = new ArrayBuffer(24);
@@ -58,11 +90,88 @@ describe("Extractors", () => {
     test(Float64Array);
   });
 
+  it("Buffer round trip", () => {
+    const input = Buffer.from("neon");
+    const output = addon.extractBufferReverse(input);
+
+    assert.ok(Buffer.isBuffer(output));
+    assert.strictEqual(output.toString(), "noen");
+    assert.strictEqual(input.toString(), "neon");
+    assert.strictEqual(addon.extractBufferReverse(Buffer.alloc(0)).length, 0);
+  });
+
   it("JSON", () => {
     assert.strictEqual(addon.extract_json_sum([1, 2, 3, 4]), 10);
     assert.strictEqual(addon.extract_json_sum([8, 16, 18]), 42);
   });
 
+  it("SystemTime", () => {
+    const HOUR = 60 * 60 * 1000;
+    const test = (ms) => {
+      const date = addon.extractSystemTimeAddHour(new Date(ms));
+
+      assert.ok(date instanceof Date);
+      assert.strictEqual(date.getTime(), ms + HOUR);
+    };
+
+    test(0);
+    test(1700000000123);
+    test(-1700000000123);
+
+    assert.throws(
+      () => addon.extractSystemTimeAddHour(new Date(NaN)),
+      (err) => err instanceof RangeError && /invalid Date/.test(err.message)
+    );
+    assert.throws(
+      () => addon.extractSystemTimeAddHour(42),
+      (err) => err instanceof TypeError && /expected.*Date/.test(err.message)
+    );
+  });
+
+  it("chrono::DateTime", () => {
+    const HOUR = 60 * 60 * 1000;
+    const test = (ms) => {
+      const date = addon.extractChronoAddHour(new Date(ms));
+
+      assert.ok(date instanceof Date);
+      assert.strictEqual(date.getTime(), ms + HOUR);
+    };
+
+    test(0);
+    test(1700000000123);
+    test(-1700000000123);
+
+    assert.throws(
+      () => addon.extractChronoAddHour(new Date(NaN)),
+      (err) => err instanceof RangeError && /invalid Date/.test(err.message)
+    );
+    assert.throws(
+      () => addon.extractChronoAddHour(42),
+      (err) => err instanceof TypeError && /expected.*Date/.test(err.message)
+    );
+  });
+
+  it("HashMap", () => {
+    assert.deepStrictEqual(addon.extractWordCounts("a b a c a b"), {
+      a: 3,
+      b: 2,
+      c: 1,
+    });
+    assert.deepStrictEqual(addon.extractWordCounts(""), {});
+  });
+
+  it("BTreeMap", () => {
+    const lengths = addon.extractSortedLengths("neon rust a javascript");
+
+    assert.deepStrictEqual(lengths, { a: 1, javascript: 10, neon: 4, rust: 4 });
+    assert.deepStrictEqual(Object.keys(lengths), [
+      "a",
+      "javascript",
+      "neon",
+      "rust",
+    ]);
+  });
+
   it("Either", () => {
     assert.strictEqual(addon.extractEither("hello"), "String: hello");
     assert.strictEqual(addon.extractEither(42), "Number: 42");
@@ -78,4 +187,21 @@ describe("Extractors", () => {
       }
     );
   });
+
+  it("JsErrorInfo", () => {
+    assert.strictEqual(
+      addon.extractErrorInfo(new RangeError("out of range")),
+      "RangeError: out of range (with stack)"
+    );
+
+    const err = new Error("no stack");
+    delete err.stack;
+    err.name = "CustomError";
+
+    assert.strictEqual(addon.extractErrorInfo(err), "CustomError: no stack");
+    assert.throws(
+      () => addon.extractErrorInfo({ name: "Error", message: "fake" }),
+      (err) => err instanceof TypeError && /expected Error/.test(err.message)
+    );
+  });
 });
@@ -25,6 +25,28 @@ describe("JsFunction", function () {
     assert.equal(addon.return_js_function()(41), 42);
   });
 
+  it("get the name of a JsFunction", function () {
+    assert.equal(addon.get_js_function_name(function named() {}), "named");
+    assert.equal(addon.get_js_function_name(() => {}), "");
+  });
+
+  it("rename a JsFunction built in Rust", function () {
+    const f = addon.return_renamed_js_function("increment");
+
+    assert.equal(f.name, "increment");
+    assert.equal(addon.get_js_function_name(f), "increment");
+    assert.equal(f(41), 42);
+  });
+
+  it("return a JsFunction built in Rust with a name and arity", function () {
+    const f = addon.return_js_function_with_arity(3);
+
+    assert.equal(f.name, "add1");
+    assert.equal(f.length, 3);
+    assert.equal(f(41), 42);
+    assert.equal(addon.return_js_function_with_arity(0).length, 0);
+  });
+
   it("call a JsFunction built in JS that implements x => x + 1", function () {
     assert.equal(
       addon.call_js_function(function (x) {
@@ -124,6 +146,21 @@ describe("JsFunction", function () {
     assert.strictEqual(result.valueOf(), 42);
   });
 
+  it("bind a JsFunction to an arbitrary value", function () {
+    const strict = addon.bind_js_function_to_value(STRICT, "hello");
+    assert.strictEqual(strict, "hello");
+
+    const sloppy = addon.bind_js_function_to_value(SLOPPY, "hello");
+    assert.instanceOf(sloppy, String);
+    assert.strictEqual(sloppy.valueOf(), "hello");
+
+    assert.strictEqual(addon.bind_js_function_to_value(STRICT, null), null);
+    assert.strictEqual(
+      addon.bind_js_function_to_value(SLOPPY, null),
+      globalThis
+    );
+  });
+
   it("call a JsFunction with zero args", function () {
     assert.equal(addon.call_js_function_with_zero_args(), -Infinity);
   });
@@ -269,6 +306,12 @@ describe("JsFunction", function () {
     assert.equal(addon.execute_scoped(), 99);
   });
 
+  it("executes a batched scoped computation", function () {
+    assert.equal(addon.execute_scoped_batched(1), 4950);
+    assert.equal(addon.execute_scoped_batched(7), 4950);
+    assert.equal(addon.execute_scoped_batched(1000), 4950);
+  });
+
   it("computes a value in a scoped computation", function () {
     const o = {};
 
@@ -312,6 +355,26 @@ describe("JsFunction", function () {
     );
   });
 
+  it("catches exceptions and panics with cx.try_catch_unwind", function () {
+    assert.strictEqual(addon.call_and_catch_unwind(() => 42, false), 42);
+    assert.strictEqual(
+      addon.call_and_catch_unwind(() => {
+        throw "shade";
+      }, false),
+      "shade"
+    );
+    assert.strictEqual(
+      addon.call_and_catch_unwind(() => 42, true),
+      "panic: plugin misbehaved"
+    );
+    assert.strictEqual(
+      addon.call_and_catch_unwind(() => {
+        throw "shade";
+      }, true),
+      "panic: plugin misbehaved"
+    );
+  });
+
   it("can return Rust type from cx.try_catch", function () {
     const n = Math.random();
     assert.strictEqual(addon.get_number_or_default(n), n);
@@ -345,10 +408,95 @@ describe("JsFunction", function () {
     assert.equal(new addon.is_construct().wasConstructed, true);
   });
 
+  it("apply a JsFunction to an array of arguments", function () {
+    assert.strictEqual(addon.apply_js_function(Math.max, Math, [1, 3, 2]), 3);
+    assert.strictEqual(addon.apply_js_function(Math.max, Math, []), -Infinity);
+
+    const obj = { base: 10 };
+    const result = addon.apply_js_function(
+      function (x, y) {
+        "use strict";
+        return [this, x, y];
+      },
+      obj,
+      ["a", "b"]
+    );
+
+    assert.deepEqual(result, [obj, "a", "b"]);
+  });
+
+  it("apply a JsFunction to too many arguments", function () {
+    const args = new Array(10_000_000).fill(0);
+
+    assert.throws(
+      () => addon.apply_js_function(Math.max, Math, args),
+      RangeError
+    );
+  });
+
+  it("should forward a range of arguments", function () {
+    const result = addon.call_with_rest_arguments(
+      (...args) => args,
+      "a",
+      2,
+      null
+    );
+
+    assert.deepEqual(result, ["a", 2, null]);
+    assert.deepEqual(addon.call_with_rest_arguments((...args) => args), []);
+  });
+
+  it("should truncate an argument range to the passed arguments", function () {
+    assert.deepEqual(addon.return_argument_slice(1, 3, "a", "b"), [3, "a"]);
+    assert.deepEqual(addon.return_argument_slice(2, 10, "a"), ["a"]);
+    assert.deepEqual(addon.return_argument_slice(5, 10), []);
+    assert.deepEqual(addon.return_argument_slice(3, 1, "a", "b"), []);
+  });
+
+  it("should get all arguments", function () {
+    assert.strictEqual(addon.count_arguments_vec(), 0);
+    assert.strictEqual(addon.count_arguments_vec(1, 2, 3), 3);
+    assert.strictEqual(addon.count_arguments_vec(...new Array(20)), 20);
+  });
+
   it("should be able to call a function from a closure", function () {
     assert.strictEqual(addon.count_called() + 1, addon.count_called());
   });
 
+  it("should be able to create a function from a FnMut closure", function () {
+    const counter = addon.make_counter_mut();
+    const other = addon.make_counter_mut();
+
+    assert.strictEqual(counter(), 1);
+    assert.strictEqual(counter(), 2);
+    assert.strictEqual(other(), 1);
+  });
+
+  it("reports whether an exception is pending", function () {
+    const pending = addon.is_exception_pending_after_call;
+
+    assert.deepEqual(
+      pending(() => {
+        throw new Error("oops");
+      }),
+      [false, true, false]
+    );
+    assert.deepEqual(pending(() => {}), [false, false, false]);
+
+    // Rejections are asynchronous and are not pending exceptions
+    const rejected = Promise.reject(new Error("async"));
+
+    rejected.catch(() => {});
+    assert.deepEqual(pending(() => rejected), [false, false, false]);
+  });
+
+  it("should throw when a FnMut closure is called reentrantly", function () {
+    const f = addon.make_reentrant_mut();
+
+    assert.throws(() => f(() => f(() => {})), /called reentrantly/);
+    assert.strictEqual(f(() => {}), 2);
+  });
+
   (global.gc ? it : it.skip)(
     "should drop function when going out of scope",
     function (cb) {
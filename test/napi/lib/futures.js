@@ -54,6 +54,32 @@ describe("Futures", () => {
         });
       }, /exception/i);
     });
+
+    it("should preserve the promise rejection reason", async () => {
+      assert.strictEqual(
+        await addon.lazy_async_settled(async () => 42),
+        "resolved: 42"
+      );
+      assert.strictEqual(
+        await addon.lazy_async_settled(async () => {
+          throw new Error("Oh, no!");
+        }),
+        "rejected: Error: Oh, no!"
+      );
+    });
+  });
+
+  describe("JsPromise::from_future", () => {
+    it("should resolve with the settled value", async () => {
+      assert.strictEqual(await addon.from_future_div(6, 3), 2);
+    });
+
+    it("should reject when settling throws", async () => {
+      await assertRejects(() => addon.from_future_div(1, 0), {
+        name: "RangeError",
+        message: "Divide by zero",
+      });
+    });
   });
 
   describe("Exported Async Functions", () => {
@@ -61,6 +87,20 @@ describe("Futures", () => {
       assert.strictEqual(await addon.asyncFnAdd(1, 2), 3);
     });
 
+    it("should call `async fn` after replacing the executor", async () => {
+      assert.strictEqual(addon.replace_global_executor(), true);
+      assert.strictEqual(await addon.asyncFnAdd(1, 2), 3);
+    });
+
+    it("should spawn `async fn` on a custom executor", async () => {
+      addon.use_counting_executor();
+
+      const before = addon.executorSpawnCount();
+
+      assert.strictEqual(await addon.asyncFnAdd(1, 2), 3);
+      assert.strictEqual(addon.executorSpawnCount(), before + 1);
+    });
+
     it("should be able to call fn with async block", async () => {
       assert.strictEqual(await addon.asyncAdd(1, 2), 3);
     });
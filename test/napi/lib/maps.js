@@ -0,0 +1,103 @@
+const addon = require("..");
+const assert = require("chai").assert;
+const vm = require("vm");
+
+describe("JsMap", function () {
+  it("should create a map and set entries", function () {
+    const key = {};
+    const map = addon.create_map_from_pairs([
+      ["a", 1],
+      [key, "object"],
+    ]);
+
+    assert.instanceOf(map, Map);
+    assert.strictEqual(map.get("a"), 1);
+    assert.strictEqual(map.get(key), "object");
+    assert.strictEqual(map.size, 2);
+  });
+
+  it("should get entries", function () {
+    const key = {};
+    const map = new Map([[key, 42]]);
+
+    assert.strictEqual(addon.map_get(map, key), 42);
+    assert.isUndefined(addon.map_get(map, {}));
+  });
+
+  it("should check for and delete entries", function () {
+    const map = new Map([
+      [1, "one"],
+      [NaN, "nan"],
+    ]);
+
+    assert.isTrue(addon.map_has(map, 1));
+    assert.isTrue(addon.map_has(map, NaN));
+    assert.isFalse(addon.map_has(map, "1"));
+
+    assert.isTrue(addon.map_delete(map, 1));
+    assert.isFalse(addon.map_delete(map, 1));
+    assert.isFalse(map.has(1));
+  });
+
+  it("should return the size", function () {
+    assert.strictEqual(addon.map_size(new Map()), 0);
+    assert.strictEqual(
+      addon.map_size(
+        new Map([
+          [1, 2],
+          [3, 4],
+        ])
+      ),
+      2
+    );
+  });
+
+  it("should iterate entries in insertion order", function () {
+    const key = {};
+    const map = new Map([
+      ["b", 2],
+      [key, "object"],
+      ["a", 1],
+    ]);
+
+    assert.deepStrictEqual(addon.map_entries(map), [
+      ["b", 2],
+      [key, "object"],
+      ["a", 1],
+    ]);
+    assert.deepStrictEqual(addon.map_entries(new Map()), []);
+  });
+
+  it("should recognize maps", function () {
+    class MyMap extends Map {}
+
+    assert.isTrue(addon.is_map(new Map()));
+    assert.isTrue(addon.is_map(new MyMap()));
+    assert.isFalse(addon.is_map(new Set()));
+    assert.isFalse(addon.is_map({}));
+    assert.isFalse(addon.is_map("map"));
+  });
+
+  it("should brand check maps", function () {
+    const { Map } = globalThis;
+    const otherRealm = vm.runInNewContext("new Map([[1, 2]])");
+
+    assert.isFalse(addon.is_map(Object.create(Map.prototype)));
+    assert.isFalse(addon.is_map(Map.prototype));
+    assert.isTrue(addon.is_map(otherRealm));
+    assert.strictEqual(addon.map_size(otherRealm), 1);
+
+    try {
+      globalThis.Map = Set;
+
+      assert.isTrue(addon.is_map(new Map()));
+      assert.isFalse(addon.is_map(new Set()));
+    } finally {
+      globalThis.Map = Map;
+    }
+  });
+
+  it("should throw when a non-map is passed", function () {
+    assert.throws(() => addon.map_size({}), TypeError);
+  });
+});
@@ -55,5 +55,57 @@ describe("JsNumber", function () {
     it("accept and return a negative number as a JsNumber", function () {
       assert.equal(addon.accept_and_return_negative_js_number(-55), -55);
     });
+
+    it("checks for safe integers", function () {
+      assert.isTrue(addon.number_is_safe_integer(42));
+      assert.isTrue(addon.number_is_safe_integer(-9007199254740991));
+      assert.isFalse(addon.number_is_safe_integer(2 ** 53));
+      assert.isFalse(addon.number_is_safe_integer(1.5));
+      assert.isFalse(addon.number_is_safe_integer(NaN));
+      assert.isFalse(addon.number_is_safe_integer(Infinity));
+    });
+
+    it("converts safe integers to i64", function () {
+      assert.strictEqual(addon.number_as_i64_checked(-55), "-55");
+      assert.strictEqual(
+        addon.number_as_i64_checked(9007199254740991),
+        "9007199254740991"
+      );
+      assert.isNull(addon.number_as_i64_checked(2 ** 53 + 1));
+      assert.isNull(addon.number_as_i64_checked(0.5));
+    });
+
+    it("parses integers with JavaScript semantics", function () {
+      assert.strictEqual(addon.parse_int_with_radix("  42px", 10), 42);
+      assert.strictEqual(addon.parse_int_with_radix("ff", 16), 255);
+      assert.strictEqual(addon.parse_int_with_radix("0x1f", 0), 31);
+      assert.strictEqual(addon.parse_int_with_radix("-101", 2), -5);
+      assert.isNaN(addon.parse_int_with_radix("px", 10));
+      assert.isNaN(addon.parse_int_with_radix("10", 37));
+    });
+
+    it("parses floats with JavaScript semantics", function () {
+      assert.strictEqual(addon.parse_float_from_string("\t3.25rem"), 3.25);
+      assert.strictEqual(addon.parse_float_from_string("1e3"), 1000);
+      assert.strictEqual(addon.parse_float_from_string("-Infinity"), -Infinity);
+      assert.isNaN(addon.parse_float_from_string("abc"));
+    });
+
+    it("parses with the original globals", function () {
+      const { parseInt, parseFloat } = globalThis;
+
+      addon.parse_int_with_radix("1", 10);
+      addon.parse_float_from_string("1");
+
+      try {
+        globalThis.parseInt = () => 0;
+        globalThis.parseFloat = () => 0;
+
+        assert.strictEqual(addon.parse_int_with_radix("7", 10), 7);
+        assert.strictEqual(addon.parse_float_from_string("7.5"), 7.5);
+      } finally {
+        Object.assign(globalThis, { parseInt, parseFloat });
+      }
+    });
   });
 });
@@ -6,6 +6,27 @@ describe("JsObject", function () {
     assert(global === addon.return_js_global_object());
   });
 
+  it("return the globalThis binding", function () {
+    assert(globalThis === addon.return_js_global_this());
+  });
+
+  it("return a replaced globalThis binding", function () {
+    const original = globalThis;
+    const replacement = {};
+
+    try {
+      globalThis = replacement;
+      assert.strictEqual(original.globalThis, replacement);
+      assert.strictEqual(addon.return_js_global_this(), replacement);
+      assert.strictEqual(addon.return_js_global_object(), original);
+
+      original.globalThis = 42;
+      assert.throws(() => addon.return_js_global_this(), TypeError);
+    } finally {
+      original.globalThis = original;
+    }
+  });
+
   it("return a JsObject built in Rust", function () {
     assert.deepEqual({}, addon.return_js_object());
   });
@@ -49,6 +70,13 @@ describe("JsObject", function () {
     });
   });
 
+  it("ignores sets from Rust on a frozen JsObject", function () {
+    const obj = addon.set_after_freeze();
+
+    assert.ok(Object.isFrozen(obj));
+    assert.deepEqual(obj, { x: 1 });
+  });
+
   it("seal a JsObject", function () {
     const obj = { x: 1 };
 
@@ -91,6 +119,277 @@ describe("JsObject", function () {
     assert.equal(addon.get_own_property_names(object).length, 1);
   });
 
+  it("filters own property keys", function () {
+    const sym = Symbol("sym");
+    const object = Object.create({ inherited: 0 });
+    object.a = 1;
+    object[2] = 2;
+    object[sym] = 3;
+    Object.defineProperty(object, "hidden", { value: 4 });
+    Object.defineProperty(object, "readonly", { value: 5, enumerable: true });
+
+    assert.deepEqual(addon.own_property_keys(object, {}), [
+      "2",
+      "a",
+      "hidden",
+      "readonly",
+      sym,
+    ]);
+    assert.deepEqual(addon.own_property_keys(object, { enumerable: true }), [
+      "2",
+      "a",
+      "readonly",
+      sym,
+    ]);
+    assert.deepEqual(addon.own_property_keys(object, { writable: true }), [
+      "2",
+      "a",
+      sym,
+    ]);
+    assert.deepEqual(addon.own_property_keys(object, { skipStrings: true }), [
+      sym,
+    ]);
+    const opts = { configurable: true, skipSymbols: true };
+    assert.deepEqual(addon.own_property_keys(object, opts), ["2", "a"]);
+  });
+
+  it("creates a Proxy", function () {
+    const target = { name: "Neon" };
+    const proxy = addon.read_only_proxy(target);
+
+    assert.strictEqual(proxy.name, "Neon");
+    assert.throws(() => {
+      proxy.name = "Rust";
+    }, /read-only/);
+    assert.strictEqual(target.name, "Neon");
+  });
+
+  it("gets the prototype of an object", function () {
+    class Animal {}
+
+    assert.strictEqual(addon.get_prototype_of(new Animal()), Animal.prototype);
+    assert.strictEqual(addon.get_prototype_of({}), Object.prototype);
+    assert.strictEqual(addon.get_prototype_of(Object.create(null)), null);
+  });
+
+  it("sets the prototype of an object", function () {
+    class Animal {}
+
+    const animal = addon.set_prototype_of({}, Animal.prototype);
+    assert.instanceOf(animal, Animal);
+
+    const bare = addon.set_prototype_of({}, null);
+    assert.strictEqual(Object.getPrototypeOf(bare), null);
+
+    assert.throws(() => addon.set_prototype_of({}, 42), TypeError);
+    assert.throws(
+      () => addon.set_prototype_of(Object.preventExtensions({}), null),
+      TypeError
+    );
+  });
+
+  it("defines a hidden data property", function () {
+    const obj = {};
+
+    addon.define_property_from(obj, "id", { value: 42 });
+
+    assert.strictEqual(obj.id, 42);
+    assert.deepEqual(Object.keys(obj), []);
+    assert.deepEqual(Object.getOwnPropertyDescriptor(obj, "id"), {
+      value: 42,
+      writable: false,
+      enumerable: false,
+      configurable: false,
+    });
+    assert.throws(
+      () => addon.define_property_from(obj, "id", { value: 1 }),
+      TypeError
+    );
+  });
+
+  it("defines a data property with attributes", function () {
+    const obj = {};
+    const sym = Symbol("sym");
+
+    addon.define_property_from(obj, sym, {
+      value: "hello",
+      writable: true,
+      enumerable: true,
+      configurable: true,
+    });
+
+    assert.deepEqual(Object.getOwnPropertyDescriptor(obj, sym), {
+      value: "hello",
+      writable: true,
+      enumerable: true,
+      configurable: true,
+    });
+  });
+
+  it("defines an accessor property", function () {
+    const obj = { first: "Ada", last: "Lovelace" };
+    let assigned;
+
+    function get() {
+      return `${this.first} ${this.last}`;
+    }
+
+    function set(value) {
+      assigned = value;
+    }
+
+    addon.define_property_from(obj, "name", { get, set, enumerable: true });
+
+    assert.strictEqual(obj.name, "Ada Lovelace");
+    obj.name = "Grace Hopper";
+    assert.strictEqual(assigned, "Grace Hopper");
+    assert.deepEqual(Object.getOwnPropertyDescriptor(obj, "name"), {
+      get,
+      set,
+      enumerable: true,
+      configurable: false,
+    });
+    assert.throws(
+      () => addon.define_property_from({}, "x", { get, value: 1 }),
+      TypeError
+    );
+  });
+
+  it("partially redefines an existing data property", function () {
+    const obj = {};
+
+    Object.defineProperty(obj, "x", {
+      value: 1,
+      writable: true,
+      enumerable: true,
+      configurable: true,
+    });
+
+    addon.define_property_from(obj, "x", { enumerable: false });
+
+    assert.deepEqual(Object.getOwnPropertyDescriptor(obj, "x"), {
+      value: 1,
+      writable: true,
+      enumerable: false,
+      configurable: true,
+    });
+
+    addon.define_property_from(obj, "x", { value: 2 });
+
+    assert.deepEqual(Object.getOwnPropertyDescriptor(obj, "x"), {
+      value: 2,
+      writable: true,
+      enumerable: false,
+      configurable: true,
+    });
+  });
+
+  it("partially redefines an existing accessor property", function () {
+    const obj = {};
+
+    function get() {
+      return 1;
+    }
+
+    function set() {}
+
+    function getAgain() {
+      return 2;
+    }
+
+    Object.defineProperty(obj, "x", { get, set, configurable: true });
+    addon.define_property_from(obj, "x", { get: getAgain });
+
+    assert.deepEqual(Object.getOwnPropertyDescriptor(obj, "x"), {
+      get: getAgain,
+      set,
+      enumerable: false,
+      configurable: true,
+    });
+  });
+
+  it("defines properties with the original Object.defineProperty", function () {
+    const { defineProperty } = Object;
+    const obj = {};
+
+    try {
+      Object.defineProperty = () => {
+        throw new Error("Object.defineProperty");
+      };
+
+      addon.define_property_from(obj, "x", { value: 1 });
+    } finally {
+      Object.defineProperty = defineProperty;
+    }
+
+    assert.strictEqual(obj.x, 1);
+  });
+
+  it("propagates exceptions when defining a property", function () {
+    const proxy = new Proxy(
+      {},
+      {
+        defineProperty() {
+          throw new Error("nope");
+        },
+      }
+    );
+
+    assert.throws(
+      () => addon.define_property_from(proxy, "x", { value: 1 }),
+      /nope/
+    );
+    assert.throws(
+      () => addon.define_property_from(proxy, "x", { get() {} }),
+      /nope/
+    );
+  });
+
+  it("reads properties with fallback values", function () {
+    assert.deepEqual(addon.read_options_with_defaults({}), {
+      host: "localhost",
+      port: 8080,
+      verbose: false,
+    });
+
+    assert.deepEqual(
+      addon.read_options_with_defaults({
+        host: "example.com",
+        port: null,
+        verbose: true,
+      }),
+      { host: "example.com", port: 8080, verbose: true }
+    );
+
+    assert.throws(
+      () => addon.read_options_with_defaults({ port: "80" }),
+      TypeError
+    );
+  });
+
+  it("checks whether a value is an instance of a constructor", function () {
+    class Animal {}
+    class Dog extends Animal {}
+
+    assert.isTrue(addon.instance_of(new Dog(), Animal));
+    assert.isTrue(addon.instance_of(new Dog(), Dog));
+    assert.isFalse(addon.instance_of(new Animal(), Dog));
+    assert.isFalse(addon.instance_of({}, Animal));
+    assert.isFalse(addon.instance_of(42, Number));
+    assert.isTrue(addon.instance_of(Buffer.alloc(1), Uint8Array));
+    assert.isTrue(addon.instance_of(new RangeError(), Error));
+  });
+
+  it("propagates exceptions thrown by an instanceof check", function () {
+    class Picky {
+      static [Symbol.hasInstance]() {
+        throw new Error("no instances");
+      }
+    }
+
+    assert.throws(() => addon.instance_of({}, Picky), /no instances/);
+  });
+
   it("data borrowed on the heap can be held longer than the handle", function () {
     const msg = "Hello, World!";
     const buf = Buffer.from(msg);
@@ -158,6 +457,29 @@ describe("JsObject", function () {
     assert.strictEqual(obj.toString(), "[object Wonder Woman]");
   });
 
+  it("calls a method with Object::call_method()", function () {
+    const obj = {
+      name: "Diana Prince",
+      setName(name) {
+        this.name = name;
+      },
+      toString() {
+        return `[object ${this.name}]`;
+      },
+      sum(...args) {
+        return args.reduce((a, b) => a + b, 0);
+      },
+    };
+
+    assert.strictEqual(
+      addon.call_method_with_tuple(obj),
+      "[object Wonder Woman]"
+    );
+    assert.strictEqual(obj.name, "Wonder Woman");
+    assert.strictEqual(addon.call_method_with_slice(obj), 6);
+    assert.throws(() => addon.call_method_with_slice({}), /not a function/);
+  });
+
   it("throws a TypeError when calling a non-method with .prop()", function () {
     const obj = {
       number: 42,
@@ -167,4 +489,97 @@ describe("JsObject", function () {
       addon.call_non_method_with_prop(obj);
     }, /not a function/);
   });
+
+  it("deletes a property by name", function () {
+    const obj = { a: 1, b: 2 };
+
+    assert.strictEqual(addon.delete_property(obj, "a"), true);
+    assert.deepEqual(obj, { b: 2 });
+  });
+
+  it("deletes a property by index", function () {
+    const arr = [1, 2, 3];
+
+    assert.strictEqual(addon.delete_property(arr, 1), true);
+    assert.isFalse(1 in arr);
+  });
+
+  it("returns false when deleting from a frozen object", function () {
+    const obj = Object.freeze({ a: 1 });
+
+    assert.strictEqual(addon.delete_property(obj, "a"), false);
+    assert.strictEqual(obj.a, 1);
+  });
+
+  it("propagates exceptions when deleting a property", function () {
+    const obj = new Proxy(
+      {},
+      {
+        deleteProperty() {
+          throw new Error("no deleting");
+        },
+      }
+    );
+
+    assert.throws(() => addon.delete_property(obj, "a"), /no deleting/);
+  });
+
+  it("checks for own and inherited properties by name", function () {
+    const proto = { inherited: 1 };
+    const obj = Object.create(proto);
+
+    obj.own = undefined;
+
+    assert.deepEqual(addon.has_property(obj, "own"), [true, true]);
+    assert.deepEqual(addon.has_property(obj, "inherited"), [true, false]);
+    assert.deepEqual(addon.has_property(obj, "missing"), [false, false]);
+  });
+
+  it("checks for properties by index", function () {
+    const arr = [1, , 3];
+
+    assert.deepEqual(addon.has_property(arr, 0), [true, true]);
+    assert.deepEqual(addon.has_property(arr, 1), [false, false]);
+  });
+
+  it("checks for properties by symbol", function () {
+    const sym = Symbol("sym");
+    const obj = { [sym]: 1 };
+
+    assert.deepEqual(addon.has_property(obj, sym), [true, true]);
+    assert.deepEqual(addon.has_property(obj, Symbol.iterator), [false, false]);
+    assert.deepEqual(addon.has_property([], Symbol.iterator), [true, false]);
+  });
+
+  it("gets a property with a symbol key", function () {
+    const sym = Symbol("sym");
+    const obj = { [sym]: 42, sym: "string key" };
+
+    assert.strictEqual(addon.get_property_with_symbol(obj, sym), 42);
+    assert.strictEqual(
+      addon.get_property_with_symbol(obj, Symbol("sym")),
+      undefined
+    );
+  });
+
+  it("sets a property with a symbol key", function () {
+    const sym = Symbol.for("neon.sym");
+    const obj = addon.set_property_with_symbol({}, sym, "hello");
+
+    assert.strictEqual(obj[sym], "hello");
+    assert.deepEqual(Object.keys(obj), []);
+  });
+
+  it("gets a property with a well-known symbol key", function () {
+    assert.strictEqual(
+      addon.get_symbol_iterator([]),
+      Array.prototype[Symbol.iterator]
+    );
+  });
+
+  it("roots an object fallibly", function () {
+    const obj = { a: 1 };
+
+    assert.strictEqual(addon.try_root_and_unwrap(obj), obj);
+  });
 });
@@ -0,0 +1,69 @@
+const addon = require("..");
+const assert = require("chai").assert;
+const vm = require("vm");
+
+describe("JsRegExp", function () {
+  it("should create a regular expression", function () {
+    const re = addon.create_regexp("a+b", "gi");
+
+    assert.instanceOf(re, RegExp);
+    assert.strictEqual(re.source, "a+b");
+    assert.strictEqual(re.flags, "gi");
+  });
+
+  it("should throw a SyntaxError for an invalid pattern", function () {
+    assert.throws(() => addon.create_regexp("(", ""), SyntaxError);
+    assert.throws(() => addon.create_regexp("a", "zz"), SyntaxError);
+  });
+
+  it("should read the source and flags", function () {
+    assert.deepStrictEqual(addon.regexp_source_and_flags(/^\d+$/mu), [
+      "^\\d+$",
+      "mu",
+    ]);
+  });
+
+  it("should test strings", function () {
+    assert.isTrue(addon.regexp_test(/^\d+$/, "12345"));
+    assert.isFalse(addon.regexp_test(/^\d+$/, "123a5"));
+  });
+
+  it("should exec strings", function () {
+    const result = addon.regexp_exec(/(\w+)@(\w+)/, "user@example");
+
+    assert.strictEqual(result[0], "user@example");
+    assert.strictEqual(result[1], "user");
+    assert.strictEqual(result[2], "example");
+    assert.strictEqual(result.index, 0);
+    assert.isNull(addon.regexp_exec(/\d/, "abc"));
+  });
+
+  it("should recognize regular expressions", function () {
+    assert.isTrue(addon.is_regexp(/neon/));
+    assert.isTrue(addon.is_regexp(new RegExp("neon")));
+    assert.isFalse(addon.is_regexp("/neon/"));
+    assert.isFalse(addon.is_regexp({ source: "neon", flags: "" }));
+  });
+
+  it("should brand check regular expressions", function () {
+    const { RegExp } = globalThis;
+    const otherRealm = vm.runInNewContext("/ne+on/g");
+
+    assert.isFalse(addon.is_regexp(Object.create(RegExp.prototype)));
+    assert.isFalse(addon.is_regexp(RegExp.prototype));
+    assert.isTrue(addon.is_regexp(otherRealm));
+    assert.deepStrictEqual(addon.regexp_source_and_flags(otherRealm), [
+      "ne+on",
+      "g",
+    ]);
+
+    try {
+      globalThis.RegExp = Map;
+
+      assert.isTrue(addon.is_regexp(/neon/));
+      assert.isFalse(addon.is_regexp(new Map()));
+    } finally {
+      globalThis.RegExp = RegExp;
+    }
+  });
+});
@@ -0,0 +1,74 @@
+const addon = require("..");
+const assert = require("chai").assert;
+const vm = require("vm");
+
+describe("JsSet", function () {
+  it("should create a set and add unique values", function () {
+    const obj = {};
+    const set = addon.create_set_from_array([1, "a", obj, 1, "a", obj]);
+
+    assert.instanceOf(set, Set);
+    assert.strictEqual(set.size, 3);
+    assert.isTrue(set.has(obj));
+  });
+
+  it("should check for and delete values", function () {
+    const obj = {};
+    const set = new Set([1, NaN, obj]);
+
+    assert.isTrue(addon.set_has(set, 1));
+    assert.isTrue(addon.set_has(set, NaN));
+    assert.isTrue(addon.set_has(set, obj));
+    assert.isFalse(addon.set_has(set, {}));
+
+    assert.isTrue(addon.set_delete(set, obj));
+    assert.isFalse(addon.set_delete(set, obj));
+    assert.isFalse(set.has(obj));
+  });
+
+  it("should return the size", function () {
+    assert.strictEqual(addon.set_size(new Set()), 0);
+    assert.strictEqual(addon.set_size(new Set([1, 2, 2, 3])), 3);
+  });
+
+  it("should iterate values in insertion order", function () {
+    const obj = {};
+    const set = new Set(["b", obj, "a"]);
+
+    assert.deepStrictEqual(addon.set_values(set), ["b", obj, "a"]);
+    assert.deepStrictEqual(addon.set_values(new Set()), []);
+  });
+
+  it("should recognize sets", function () {
+    class MySet extends Set {}
+
+    assert.isTrue(addon.is_set(new Set()));
+    assert.isTrue(addon.is_set(new MySet()));
+    assert.isFalse(addon.is_set(new Map()));
+    assert.isFalse(addon.is_set([]));
+    assert.isFalse(addon.is_set(undefined));
+  });
+
+  it("should brand check sets", function () {
+    const { Set } = globalThis;
+    const otherRealm = vm.runInNewContext("new Set([1, 2])");
+
+    assert.isFalse(addon.is_set(Object.create(Set.prototype)));
+    assert.isFalse(addon.is_set(Set.prototype));
+    assert.isTrue(addon.is_set(otherRealm));
+    assert.strictEqual(addon.set_size(otherRealm), 2);
+
+    try {
+      globalThis.Set = Map;
+
+      assert.isTrue(addon.is_set(new Set()));
+      assert.isFalse(addon.is_set(new Map()));
+    } finally {
+      globalThis.Set = Set;
+    }
+  });
+
+  it("should throw when a non-set is passed", function () {
+    assert.throws(() => addon.set_size([]), TypeError);
+  });
+});
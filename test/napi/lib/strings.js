@@ -17,6 +17,61 @@ describe("JsString", function () {
       assert.equal(addon.return_length_utf16("hello 🥹"), 8);
     });
   });
+  describe("try_new_truncated", function () {
+    it("should return the full string when it fits", function () {
+      assert.equal(addon.truncate_string("hello", 10), "hello");
+    });
+    it("should return the full string when exactly at the limit", function () {
+      assert.equal(addon.truncate_string("hello 🥹", 10), "hello 🥹");
+    });
+    it("should truncate and append the suffix", function () {
+      assert.equal(addon.truncate_string("hello world", 5), "hello…");
+    });
+    it("should not split a multi-byte character", function () {
+      assert.equal(addon.truncate_string("hello 🥹", 9), "hello …");
+      assert.equal(addon.truncate_string("hello 🥹", 7), "hello …");
+      assert.equal(addon.truncate_string("🥹🥹", 3), "…");
+    });
+  });
+  describe("value_lossy", function () {
+    it("should round-trip a well-formed string", function () {
+      assert.equal(addon.return_string_value_lossy("hello 🥹"), "hello 🥹");
+    });
+    it("should replace a lone surrogate", function () {
+      assert.equal(addon.return_string_value_lossy("a\uD83Eb"), "a\uFFFDb");
+    });
+  });
+  describe("from_utf16", function () {
+    it("should round-trip a UTF-16 string", function () {
+      assert.equal(addon.roundtrip_string_utf16("hello 🥹"), "hello 🥹");
+      assert.equal(
+        addon.roundtrip_string_utf16("\u00e9t\u00e9 \u4e2d"),
+        "été 中"
+      );
+      assert.equal(addon.roundtrip_string_utf16(""), "");
+    });
+  });
+  describe("value_into", function () {
+    it("should replace the contents of the buffer", function () {
+      assert.equal(
+        addon.join_strings_value_into(["a longer string", "", "hi", "🥹"]),
+        "a longer string||hi|🥹|"
+      );
+    });
+  });
+  describe("to_cstring", function () {
+    it("should include the nul terminator", function () {
+      assert.equal(addon.cstring_byte_len("hello"), 6);
+      assert.equal(addon.cstring_byte_len("🥹"), 5);
+      assert.equal(addon.cstring_byte_len(""), 1);
+    });
+    it("should reject interior nul characters", function () {
+      expect(() => addon.cstring_byte_len("a\0b")).to.throw(
+        TypeError,
+        /nul byte found/
+      );
+    });
+  });
   describe("run_as_script", function () {
     it("should return the evaluated value", function () {
       assert.equal(addon.run_string_as_script("6 * 7"), 42);
@@ -32,4 +87,29 @@ describe("JsString", function () {
       );
     });
   });
+  describe("run_script", function () {
+    it("should return the evaluated value", function () {
+      assert.equal(addon.run_string_as_named_script("6 * 7", "answer.js"), 42);
+    });
+    it("should record the script name in stack traces", function () {
+      try {
+        addon.run_string_as_named_script(
+          'const x = 1;\nthrow new Error("b1-66er")',
+          "my-script.js"
+        );
+        assert.fail("expected script to throw");
+      } catch (err) {
+        assert.equal(err.message, "b1-66er");
+        assert.include(err.stack, "my-script.js:2:7");
+      }
+    });
+    it("should encode whitespace in the script name", function () {
+      try {
+        addon.run_string_as_named_script("throw new Error()", "my script.js");
+        assert.fail("expected script to throw");
+      } catch (err) {
+        assert.include(err.stack, "my%20script.js:1:7");
+      }
+    });
+  });
 });
@@ -0,0 +1,48 @@
+const addon = require("..");
+const assert = require("chai").assert;
+
+describe("JsSymbol", function () {
+  it("should create a unique symbol with a description", function () {
+    const sym = addon.create_symbol("neon");
+
+    assert.strictEqual(typeof sym, "symbol");
+    assert.strictEqual(sym.description, "neon");
+    assert.notStrictEqual(sym, addon.create_symbol("neon"));
+  });
+
+  it("should create a symbol without a description", function () {
+    const sym = addon.create_symbol();
+
+    assert.strictEqual(typeof sym, "symbol");
+    assert.isUndefined(sym.description);
+  });
+
+  it("should get a symbol from the global registry", function () {
+    assert.strictEqual(addon.symbol_for("neon"), Symbol.for("neon"));
+    assert.strictEqual(addon.symbol_for("neon"), addon.symbol_for("neon"));
+  });
+
+  it("should read the description of a symbol", function () {
+    assert.strictEqual(addon.symbol_description(Symbol("hello")), "hello");
+    assert.strictEqual(addon.symbol_description(Symbol("")), "");
+    assert.isUndefined(addon.symbol_description(Symbol()));
+    assert.strictEqual(
+      addon.symbol_description(Symbol.iterator),
+      "Symbol.iterator"
+    );
+  });
+
+  it("should downcast symbols", function () {
+    assert.isTrue(addon.is_symbol(Symbol()));
+    assert.isFalse(addon.is_symbol("Symbol()"));
+    assert.isFalse(addon.is_symbol({}));
+    assert.throws(() => addon.symbol_description("hello"), TypeError);
+  });
+
+  it("should use a symbol as a property key", function () {
+    const obj = addon.set_symbol_property();
+
+    assert.strictEqual(obj[Symbol.for("neon.key")], "value");
+    assert.deepEqual(Object.keys(obj), []);
+  });
+});
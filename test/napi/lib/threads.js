@@ -33,6 +33,14 @@ const assert = require("chai").assert;
     addon.thread_callback(cb);
   });
 
+  it("should be able to call a rooted callback from another thread", function (cb) {
+    addon.channel_call_root(function (message, n) {
+      assert.strictEqual(message, "hello");
+      assert.strictEqual(n, 42);
+      cb();
+    });
+  });
+
   it("should be able to callback from multiple threads", function (cb) {
     const n = 4;
     const set = new Set([...new Array(n)].map((_, i) => i));
@@ -76,6 +84,20 @@ const assert = require("chai").assert;
     global.gc();
   });
 
+  it("should report and toggle the channel reference state", function () {
+    assert.deepStrictEqual(addon.channel_reference_state(), [
+      true,
+      true,
+      false,
+      false,
+      false,
+      true,
+      true,
+      true,
+      false,
+    ]);
+  });
+
   it("should be able to unref channel", function () {
     // If the Channel is not unreferenced, the test runner will not cleanly exit
     addon.leak_channel();
@@ -113,6 +135,23 @@ const assert = require("chai").assert;
     }, 10);
   });
 
+  it("should be able to wait on the result of a channel", function (cb) {
+    addon.channel_send_and_wait(
+      () => "Hello, World!",
+      (res) => {
+        assert.strictEqual(res, "Received: Hello, World!");
+        cb();
+      }
+    );
+  });
+
+  it("should panic when waiting on a channel from the JavaScript thread", function () {
+    assert.throws(
+      () => addon.channel_send_and_wait_on_js_thread(),
+      /cannot be called from the JavaScript thread/
+    );
+  });
+
   it("should be able to sum numbers on the libuv pool", async function () {
     const nums = new Float64Array(
       [...new Array(10000)].map(() => Math.random())
@@ -459,6 +498,42 @@ const assert = require("chai").assert;
     }
   });
 
+  it("should adopt a promise returned from a task's complete callback", async function () {
+    assert.strictEqual(await addon.task_chain_promise("4"), 16);
+
+    try {
+      await addon.task_chain_promise("four");
+
+      throw new Error("Did not throw");
+    } catch (err) {
+      assert.instanceOf(err, TypeError);
+      assert.strictEqual(err.message, "expected a number");
+    }
+  });
+
+  it("should reject the promise of a task cancelled before it starts", async function () {
+    try {
+      await addon.task_cancel_before_start();
+
+      throw new Error("Did not throw");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, "task was cancelled");
+    }
+  });
+
+  it("should be able to cooperatively cancel a running task", async function () {
+    const [promise, task] = addon.task_wait_for_cancel();
+
+    while (!addon.task_started(task)) {
+      await new Promise((resolve) => setTimeout(resolve, 1));
+    }
+
+    addon.cancel_task(task);
+
+    assert.typeOf(await promise, "number");
+  });
+
   it("should be able to reject a promise settling with a channel", async function () {
     const msg = "Rejected!";
 
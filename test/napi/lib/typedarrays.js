@@ -160,6 +160,17 @@ describe("Typed arrays", function () {
     }
   });
 
+  it("gets a Buffer copied from a slice", function () {
+    var b = addon.return_buffer_from_slice("hello 🥹");
+    assert.instanceOf(b, Buffer);
+    assert.strictEqual(b.length, 10);
+    assert.strictEqual(b.toString(), "hello 🥹");
+
+    var empty = addon.return_buffer_from_slice("");
+    assert.instanceOf(empty, Buffer);
+    assert.strictEqual(empty.length, 0);
+  });
+
   it("gets an external Buffer", function () {
     var expected = "String to copy";
     var buf = addon.return_external_buffer(expected);
@@ -174,6 +185,34 @@ describe("Typed arrays", function () {
     assert.strictEqual(Buffer.from(buf).toString(), expected);
   });
 
+  it("gets an ArrayBuffer that owns a Vec", function () {
+    var expected = "String to move";
+    var buf = addon.return_array_buffer_from_vec(expected);
+    assert.instanceOf(buf, ArrayBuffer);
+    assert.strictEqual(Buffer.from(buf).toString(), expected);
+  });
+
+  (global.gc ? it : it.skip)(
+    "drops the data of an external ArrayBuffer when garbage collected",
+    async function () {
+      const before = addon.external_drop_count();
+
+      // Run from an `IIFE` to ensure that the buffer is out of scope and eligible for
+      // garbage collection when `global.gc()` is executed.
+      (() => {
+        const buf = addon.return_counted_external_array_buffer(1024);
+        assert.strictEqual(buf.byteLength, 1024);
+      })();
+
+      for (let i = 0; i < 10 && addon.external_drop_count() === before; i++) {
+        global.gc();
+        await new Promise((resolve) => setImmediate(resolve));
+      }
+
+      assert.strictEqual(addon.external_drop_count(), before + 1);
+    }
+  );
+
   it("gets a typed array constructed from an ArrayBuffer", function () {
     var b = new ArrayBuffer(64);
     var i8 = addon.return_int8array_from_arraybuffer(b);
@@ -313,6 +352,38 @@ describe("Typed arrays", function () {
     assert.strictEqual(128, info.byteLength);
   });
 
+  it("copies a typed array into a Vec", function () {
+    var buf = new ArrayBuffer(64);
+    var whole = new Float64Array(buf);
+    var view = new Float64Array(buf, 16, 3);
+    whole.set([0, 1, 2, 3, 4, 5, 6, 7]);
+
+    assert.deepEqual(addon.float64array_to_array(whole), [
+      0, 1, 2, 3, 4, 5, 6, 7,
+    ]);
+    assert.deepEqual(addon.float64array_to_array(view), [2, 3, 4]);
+    assert.deepEqual(addon.float64array_to_array(new Float64Array(0)), []);
+  });
+
+  it("creates a subarray sharing the same buffer", function () {
+    var buf = new ArrayBuffer(64);
+    var view = new Uint32Array(buf, 8, 10);
+    view.set([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+    var sub = addon.uint32array_subarray(view, 2, 5);
+    assert.instanceOf(sub, Uint32Array);
+    assert.strictEqual(sub.buffer, buf);
+    assert.strictEqual(sub.byteOffset, 16);
+    assert.deepEqual(Array.from(sub), [2, 3, 4]);
+
+    sub[0] = 42;
+    assert.strictEqual(view[2], 42);
+
+    assert.strictEqual(addon.uint32array_subarray(view, 10, 10).length, 0);
+    assert.throws(() => addon.uint32array_subarray(view, 4, 11), RangeError);
+    assert.throws(() => addon.uint32array_subarray(view, 5, 4), RangeError);
+  });
+
   it("correctly constructs a view over a slice of a buffer", function () {
     var buf = new ArrayBuffer(128);
 
@@ -433,10 +504,39 @@ describe("Typed arrays", function () {
     assert.strictEqual(buf.byteLength, 16);
     assert.strictEqual(addon.get_arraybuffer_byte_length(buf), 16);
 
+    assert.strictEqual(addon.is_array_buffer_detached(buf), false);
+
     detach(buf);
 
     assert.strictEqual(buf.byteLength, 0);
     assert.strictEqual(addon.get_arraybuffer_byte_length(buf), 0);
+    assert.strictEqual(addon.is_array_buffer_detached(buf), true);
+  });
+
+  it("detaches an ArrayBuffer from Rust", function () {
+    var buf = new ArrayBuffer(16);
+    var view = new Uint8Array(buf);
+
+    view[0] = 42;
+
+    assert.strictEqual(addon.is_array_buffer_detached(buf), false);
+    assert.deepEqual(addon.detach_array_buffer(buf, view), [16, 0, 0]);
+    assert.strictEqual(addon.is_array_buffer_detached(buf), true);
+    assert.strictEqual(buf.byteLength, 0);
+    assert.strictEqual(view.length, 0);
+    assert.strictEqual(view[0], undefined);
+  });
+
+  it("throws when detaching a non-detachable ArrayBuffer", function () {
+    var memory = new WebAssembly.Memory({ initial: 1 });
+    var view = new Uint8Array(memory.buffer);
+
+    assert.throws(
+      () => addon.detach_array_buffer(memory.buffer, view),
+      TypeError,
+      /not detachable/
+    );
+    assert.strictEqual(memory.buffer.byteLength, 65536);
   });
 
   function testDetach(
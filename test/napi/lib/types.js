@@ -90,4 +90,150 @@ describe("type checks", function () {
     assert(!addon.strict_equals(o1, o2));
     assert(!addon.strict_equals(o1, 17));
   });
+
+  it("loose_equals", function () {
+    assert(addon.loose_equals(17, 17));
+    assert(addon.loose_equals(17, "17"));
+    assert(addon.loose_equals(null, undefined));
+    assert(!addon.loose_equals(17, 18));
+    assert(!addon.loose_equals(null, 0));
+    let o1 = {};
+    let o2 = {};
+    assert(addon.loose_equals(o1, o1));
+    assert(!addon.loose_equals(o1, o2));
+    assert(addon.loose_equals({ valueOf: () => 17 }, 17));
+    assert.throws(
+      () =>
+        addon.loose_equals(
+          {
+            valueOf() {
+              throw new Error("nope");
+            },
+          },
+          17
+        ),
+      /nope/
+    );
+  });
+
+  it("loose_equals agrees with ==", function () {
+    const sym = Symbol("sym");
+    const values = [
+      undefined,
+      null,
+      true,
+      false,
+      0,
+      -0,
+      1,
+      16,
+      1.5,
+      NaN,
+      Infinity,
+      2 ** 53,
+      "",
+      " ",
+      "0",
+      "1",
+      " 16 ",
+      "0x10",
+      "1.5",
+      "1n",
+      "true",
+      "sym",
+      "default",
+      "[object Object]",
+      0n,
+      1n,
+      16n,
+      2n ** 53n,
+      sym,
+      Symbol("sym"),
+      {},
+      [],
+      [1],
+      ["1", "2"],
+      new Date(0),
+      { valueOf: () => 1 },
+      { valueOf: () => 16n },
+      { valueOf: () => null },
+      { valueOf: () => sym },
+      { toString: () => "0x10", valueOf: () => ({}) },
+      { [Symbol.toPrimitive]: (hint) => hint },
+      Object(1),
+      Object("1"),
+      Object(1n),
+      Object(sym),
+      function () {},
+    ];
+
+    values.forEach((a, i) => {
+      values.forEach((b, j) => {
+        assert.strictEqual(
+          addon.loose_equals(a, b),
+          a == b,
+          `values[${i}] == values[${j}]`
+        );
+      });
+    });
+  });
+
+  it("loose_equals does not use the Function global", function () {
+    const { Function } = globalThis;
+
+    try {
+      globalThis.Function = function () {
+        throw new Error("Function global");
+      };
+
+      assert(addon.loose_equals(17, "17"));
+    } finally {
+      globalThis.Function = Function;
+    }
+  });
+
+  it("loose_equals throws if an object cannot be converted", function () {
+    const noPrimitive = { valueOf: () => ({}), toString: () => ({}) };
+    const badToPrimitive = { [Symbol.toPrimitive]: () => ({}) };
+
+    assert.throws(() => addon.loose_equals(noPrimitive, 1), TypeError);
+    assert.throws(() => addon.loose_equals(1, badToPrimitive), TypeError);
+    assert.isFalse(addon.loose_equals(noPrimitive, null));
+    assert.throws(() => noPrimitive == 1, TypeError);
+  });
+
+  it("type_of", function () {
+    const values = [
+      undefined,
+      null,
+      true,
+      17,
+      "hello",
+      Symbol("sym"),
+      {},
+      [],
+      function () {},
+      10n,
+    ];
+
+    for (const value of values) {
+      assert.strictEqual(addon.type_of(value), typeof value);
+    }
+  });
+
+  it("class_name", function () {
+    class Point {}
+
+    assert.strictEqual(addon.class_name(undefined), "undefined");
+    assert.strictEqual(addon.class_name(null), "null");
+    assert.strictEqual(addon.class_name(17), "Number");
+    assert.strictEqual(addon.class_name("hello"), "String");
+    assert.strictEqual(addon.class_name({}), "Object");
+    assert.strictEqual(addon.class_name([]), "Array");
+    assert.strictEqual(addon.class_name(new Map()), "Map");
+    assert.strictEqual(addon.class_name(new Point()), "Point");
+    assert.strictEqual(addon.class_name(() => {}), "Function");
+    assert.strictEqual(addon.class_name(Object.create(null)), "object");
+    assert.strictEqual(addon.class_name({ constructor: 17 }), "object");
+  });
 });
@@ -33,6 +33,15 @@ if (!isMainThread) {
         case "get_or_init_clone":
           addon.get_or_init_clone(() => ({}));
           break;
+        case "try_get_and_replace":
+          parentPort.postMessage(addon.try_get_and_replace({}));
+          break;
+        case "replace_local_message":
+          parentPort.postMessage(addon.replace_local_message("worker"));
+          break;
+        case "get_instance_message":
+          parentPort.postMessage(addon.get_instance_message());
+          break;
         case "get_thread_id":
           {
             let id = addon.get_or_init_thread_id(NaN);
@@ -53,6 +62,12 @@ if (!isMainThread) {
     parentPort.postMessage("startup_complete");
   }
 
+  if (workerData === "box_with_drop") {
+    // Keep the box reachable so that it is only cleaned up by environment teardown
+    global.boxWithDrop = addon.box_with_drop();
+    parentPort.postMessage("boxed");
+  }
+
   return;
 }
 
@@ -72,6 +87,14 @@ describe("Worker / Root Tagging Tests", () => {
       assert.strictEqual(addon.get_and_replace({}), second);
     });
 
+    it("should be able to stash a global with `try_get_and_replace`", () => {
+      const first = {};
+      const second = {};
+
+      assert.strictEqual(addon.try_get_and_replace(first), undefined);
+      assert.strictEqual(addon.try_get_and_replace(second), first);
+    });
+
     it("should be able to lazily initialize with `get_or_init`", () => {
       const o = {};
 
@@ -135,6 +158,24 @@ describe("Worker / Root Tagging Tests", () => {
 
       worker.postMessage("get_or_init_clone");
     });
+
+    it("should return the `Root` intact from `try_get_and_replace`", (cb) => {
+      const worker = new Worker(__filename);
+
+      worker.once("message", (message) => {
+        assert.strictEqual(message, null);
+
+        // The root stashed by the main thread is still available
+        const next = {};
+        const previous = addon.try_get_and_replace(next);
+
+        assert.ok(previous !== null && typeof previous === "object");
+        assert.strictEqual(addon.try_get_and_replace({}), next);
+        cb();
+      });
+
+      worker.postMessage("try_get_and_replace");
+    });
   });
 });
 
@@ -150,6 +191,47 @@ describe("Instance-local storage", () => {
     assert.strictEqual(global, addon.unstash_global_object());
   });
 
+  it("should be able to mutate an instance local", () => {
+    assert.strictEqual(addon.replace_local_message("first"), undefined);
+    assert.strictEqual(addon.replace_local_message("second"), "first");
+  });
+
+  it("should not share instance locals with workers", (cb) => {
+    const worker = new Worker(__filename);
+
+    worker.once("message", (message) => {
+      // The worker started with its own uninitialized storage
+      assert.strictEqual(message, undefined);
+      assert.strictEqual(addon.replace_local_message("third"), "second");
+      cb();
+    });
+
+    worker.postMessage("replace_local_message");
+  });
+
+  it("should store and replace instance data", () => {
+    addon.set_instance_message("first");
+    assert.strictEqual(addon.get_instance_message(), "first");
+    assert.strictEqual(addon.get_instance_data_as_string(), false);
+
+    addon.set_instance_message("second");
+    assert.strictEqual(addon.get_instance_message(), "second");
+  });
+
+  it("should not share instance data with workers", (cb) => {
+    const worker = new Worker(__filename);
+
+    addon.set_instance_message("main");
+
+    worker.once("message", (message) => {
+      assert.strictEqual(message, undefined);
+      assert.strictEqual(addon.get_instance_message(), "main");
+      cb();
+    });
+
+    worker.postMessage("get_instance_message");
+  });
+
   it("should gracefully panic upon reentrant get_or_try_init", () => {
     // 1. Global should start out uninitialized
     assert.strictEqual(null, addon.get_reentrant_value());
@@ -202,6 +284,22 @@ describe("Instance-local storage", () => {
     worker.postMessage("get_thread_id");
   });
 
+  it("should drop a `JsBox::new_with_drop` value when a worker exits", (cb) => {
+    const before = addon.box_with_drop_count();
+    const worker = new Worker(__filename, { workerData: "box_with_drop" });
+
+    worker.once("message", async () => {
+      await worker.terminate();
+
+      try {
+        assert.strictEqual(addon.box_with_drop_count(), before + 1);
+        cb();
+      } catch (err) {
+        cb(err);
+      }
+    });
+  });
+
   it("should be able to exit a worker without a crash", (cb) => {
     const worker = new Worker(__filename, {
       workerData: "notify_when_startup_complete",
@@ -24,3 +24,84 @@ pub fn read_js_array(mut cx: FunctionContext) -> JsResult<JsValue> {
 
     Ok(first_element)
 }
+
+pub fn return_js_array_from_slice(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let items = [
+        cx.number(1).upcast::<JsValue>(),
+        cx.string("two").upcast(),
+        cx.boolean(true).upcast(),
+    ];
+
+    JsArray::from_slice(&mut cx, &items)
+}
+
+pub fn return_empty_js_array_from_slice(mut cx: FunctionContext) -> JsResult<JsArray> {
+    JsArray::from_slice::<_, JsValue>(&mut cx, &[])
+}
+
+pub fn find_first_string_in_js_array(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let mut visited = 0;
+    let mut found = None;
+
+    let mut iter = array.iter(&mut cx);
+
+    while let Some(item) = iter.next() {
+        let item = item?;
+
+        visited += 1;
+
+        if item.is_a::<JsString, _>(iter.cx()) {
+            found = Some(item);
+            break;
+        }
+    }
+
+    let found = match found {
+        Some(found) => found,
+        None => cx.undefined().upcast(),
+    };
+
+    let result = cx.empty_array();
+    let visited = cx.number(visited);
+
+    result.set(&mut cx, 0, found)?;
+    result.set(&mut cx, 1, visited)?;
+
+    Ok(result.upcast())
+}
+
+pub fn count_js_array_iter(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let mut count = 0;
+
+    for item in array.iter(&mut cx) {
+        item?;
+        count += 1;
+    }
+
+    Ok(cx.number(count))
+}
+
+pub fn push_js_array(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let value: Handle<JsValue> = cx.argument(1)?;
+    let len = array.push(&mut cx, value)?;
+
+    Ok(cx.number(len))
+}
+
+pub fn pop_js_array(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+
+    array.pop(&mut cx)
+}
+
+pub fn splice_js_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let start = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+    let delete_count = cx.argument::<JsNumber>(2)?.value(&mut cx) as u32;
+    let items = cx.argument_slice(3, usize::MAX);
+
+    array.splice(&mut cx, start, delete_count, &items)
+}
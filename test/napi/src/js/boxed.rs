@@ -38,6 +38,30 @@ pub fn person_greet(mut cx: FunctionContext) -> JsResult<JsString> {
     Ok(greeting)
 }
 
+pub fn person_new_with_finalizer(mut cx: FunctionContext) -> JsResult<JsBox<String>> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+    let channel = cx.channel();
+
+    // `String` does not implement `Finalize`
+    Ok(JsBox::new_with_finalizer(&mut cx, name, move |_, name| {
+        channel.send(move |mut cx| {
+            callback
+                .into_inner(&mut cx)
+                .bind(&mut cx)
+                .arg(format!("Goodbye, {name}!"))?
+                .exec()
+        });
+    }))
+}
+
+pub fn boxed_string_value(mut cx: FunctionContext) -> JsResult<JsString> {
+    let name = cx.argument::<JsBox<String>>(0)?;
+    let name = cx.string(&**name);
+
+    Ok(name)
+}
+
 pub fn ref_person_new(mut cx: FunctionContext) -> JsResult<JsValue> {
     let name = cx.argument::<JsString>(0)?.value(&mut cx);
     let person = RefCell::new(Person::new(name));
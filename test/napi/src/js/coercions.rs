@@ -4,3 +4,18 @@ pub fn to_string(mut cx: FunctionContext) -> JsResult<JsString> {
     let arg: Handle<JsValue> = cx.argument(0)?;
     arg.to_string(&mut cx)
 }
+
+pub fn coerce_to_number(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let arg: Handle<JsValue> = cx.argument(0)?;
+    cx.coerce_to_number(arg)
+}
+
+pub fn coerce_to_string(mut cx: FunctionContext) -> JsResult<JsString> {
+    let arg: Handle<JsValue> = cx.argument(0)?;
+    cx.coerce_to_string(arg)
+}
+
+pub fn coerce_to_boolean(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let arg: Handle<JsValue> = cx.argument(0)?;
+    cx.coerce_to_boolean(arg)
+}
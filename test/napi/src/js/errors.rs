@@ -24,6 +24,27 @@ pub fn throw_error(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     cx.throw_error(msg)
 }
 
+pub fn new_error_with_code(mut cx: FunctionContext) -> JsResult<JsError> {
+    let code = cx.argument::<JsString>(0)?.value(&mut cx);
+    let msg = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    cx.error_with_code(code, msg)
+}
+
+pub fn throw_error_with_code(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let code = cx.argument::<JsString>(0)?.value(&mut cx);
+    let msg = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    cx.throw_error_with_code(code, msg)
+}
+
+pub fn new_error_with_cause(mut cx: FunctionContext) -> JsResult<JsError> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let cause = cx.argument::<JsValue>(1)?;
+
+    cx.error_with_cause(msg, cause)
+}
+
 pub fn downcast_error(mut cx: FunctionContext) -> JsResult<JsString> {
     let s = cx.string("hi");
     if let Err(e) = s.downcast::<JsNumber, _>(&mut cx) {
@@ -32,3 +53,50 @@ pub fn downcast_error(mut cx: FunctionContext) -> JsResult<JsString> {
         panic!()
     }
 }
+
+pub fn downcast_or_throw_number(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    cx.argument::<JsValue>(0)?.downcast_or_throw(&mut cx)
+}
+
+pub fn error_kind(mut cx: FunctionContext) -> JsResult<JsString> {
+    let err = cx.argument::<JsError>(0)?;
+    let kind = err.kind(&mut cx)?;
+
+    Ok(cx.string(kind.as_str()))
+}
+
+pub fn is_type_or_range_error(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let err = cx.argument::<JsError>(0)?;
+    let is_type_error = err.is_type_error(&mut cx)?;
+    let is_range_error = err.is_range_error(&mut cx)?;
+    let result = cx.empty_array();
+
+    result.prop(&mut cx, 0).set(is_type_error)?;
+    result.prop(&mut cx, 1).set(is_range_error)?;
+
+    Ok(result)
+}
+
+pub fn capture_stack(mut cx: FunctionContext) -> JsResult<JsString> {
+    let stack = cx.capture_stack()?;
+
+    Ok(cx.string(stack))
+}
+
+pub fn throw_system_error(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let path = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.system_error("ENOENT", "no such file or directory")
+        .errno(-2)
+        .syscall("open")
+        .path(path)
+        .throw()
+}
+
+pub fn build_system_error(mut cx: FunctionContext) -> JsResult<JsError> {
+    cx.system_error("EXDEV", "cross-device link not permitted")
+        .syscall("rename")
+        .path("/tmp/a")
+        .dest("/home/b")
+        .build()
+}
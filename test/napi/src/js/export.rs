@@ -31,6 +31,16 @@ fn rs_renamed_add(a: f64, b: f64) -> f64 {
     simple_add(a, b)
 }
 
+#[neon::export]
+fn get_user_by_id(id: f64) -> String {
+    format!("user-{id}")
+}
+
+#[neon::export(name = "fetch_user_by_id")]
+fn fetch_user_by_id(id: f64) -> String {
+    get_user_by_id(id)
+}
+
 #[neon::export(task)]
 fn add_task(a: f64, b: f64) -> f64 {
     simple_add(a, b)
@@ -85,6 +95,16 @@ fn fail_with_throw(msg: String) -> Result<(), Error> {
     Ok(())
 }
 
+#[neon::export]
+fn parse_int(s: String) -> Result<f64, std::num::ParseIntError> {
+    s.parse::<i32>().map(f64::from)
+}
+
+#[neon::export(task)]
+fn parse_int_task(s: String) -> Result<f64, std::num::ParseIntError> {
+    parse_int(s)
+}
+
 #[neon::export(task)]
 fn sleep_task(ms: f64) {
     use std::{thread, time::Duration};
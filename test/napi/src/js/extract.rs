@@ -1,3 +1,8 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::{Duration, SystemTime},
+};
+
 use either::Either;
 use neon::{prelude::*, types::extract::*};
 
@@ -56,6 +61,34 @@ pub fn extract_values(mut cx: FunctionContext) -> JsResult<JsArray> {
     Ok(arr)
 }
 
+pub fn extract_optional_trailing(mut cx: FunctionContext) -> JsResult<JsString> {
+    let (name, greeting): (String, Option<String>) = cx.args()?;
+    let greeting = greeting.as_deref().unwrap_or("Hello");
+
+    Ok(cx.string(format!("{greeting}, {name}!")))
+}
+
+pub fn extract_rest_join(mut cx: FunctionContext) -> JsResult<JsString> {
+    let (separator, Rest(parts)): (String, Rest<String>) = cx.args()?;
+
+    Ok(cx.string(parts.join(&separator)))
+}
+
+#[neon::export]
+pub fn extract_rest_sum(nums: Rest<f64>) -> f64 {
+    let Rest(nums) = nums;
+
+    nums.into_iter().fold(0.0, |sum, n| sum + n)
+}
+
+pub fn extract_args_with_this(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let (this, (first, last)): (Handle<JsObject>, (String, String)) = cx.args_with_this()?;
+
+    this.prop(&mut cx, "name").set(format!("{first} {last}"))?;
+
+    Ok(cx.undefined())
+}
+
 pub fn extract_buffer_sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
     fn sum<'cx, T>(
         cx: &mut FunctionContext<'cx>,
@@ -145,3 +178,55 @@ pub fn extract_either(either: Either<String, f64>) -> String {
         Either::Right(n) => format!("Number: {n}"),
     }
 }
+
+#[neon::export]
+pub fn extract_system_time_add_hour(time: SystemTime) -> SystemTime {
+    time + Duration::from_secs(60 * 60)
+}
+
+#[neon::export]
+pub fn extract_chrono_add_hour(
+    time: chrono::DateTime<chrono::Utc>,
+) -> chrono::DateTime<chrono::Utc> {
+    time + chrono::Duration::hours(1)
+}
+
+#[neon::export]
+pub fn extract_word_counts(text: String) -> HashMap<String, f64> {
+    let mut counts = HashMap::new();
+
+    for word in text.split_whitespace() {
+        *counts.entry(word.to_string()).or_insert(0.0) += 1.0;
+    }
+
+    counts
+}
+
+#[neon::export]
+pub fn extract_sorted_lengths(text: String) -> BTreeMap<String, f64> {
+    text.split_whitespace()
+        .map(|word| (word.to_string(), word.len() as f64))
+        .collect()
+}
+
+#[neon::export]
+pub fn extract_buffer_reverse(buf: Buffer) -> Buffer {
+    let Buffer(mut bytes) = buf;
+
+    bytes.reverse();
+    Buffer(bytes)
+}
+
+#[neon::export]
+pub fn extract_error_info(err: JsErrorInfo) -> String {
+    let JsErrorInfo {
+        name,
+        message,
+        stack,
+    } = err;
+
+    match stack {
+        Some(_) => format!("{name}: {message} (with stack)"),
+        None => format!("{name}: {message}"),
+    }
+}
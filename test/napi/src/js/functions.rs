@@ -1,4 +1,4 @@
-use neon::{prelude::*, types::extract::With};
+use neon::{context::CaughtError, prelude::*, types::extract::With};
 
 fn add1(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let x = cx.argument::<JsNumber>(0)?.value(&mut cx);
@@ -9,6 +9,28 @@ pub fn return_js_function(mut cx: FunctionContext) -> JsResult<JsFunction> {
     JsFunction::new(&mut cx, add1)
 }
 
+pub fn return_renamed_js_function(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+    let f = JsFunction::new(&mut cx, add1)?;
+
+    f.set_name(&mut cx, &name)?;
+
+    Ok(f)
+}
+
+pub fn return_js_function_with_arity(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let length = cx.argument::<JsNumber>(0)?.value(&mut cx);
+
+    JsFunction::with_arity(&mut cx, "add1", length as usize, add1)
+}
+
+pub fn get_js_function_name(mut cx: FunctionContext) -> JsResult<JsString> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let name = f.name(&mut cx)?;
+
+    Ok(cx.string(name))
+}
+
 pub fn call_js_function(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let f = cx.argument::<JsFunction>(0)?;
     let args = [cx.number(16.0).upcast()];
@@ -85,6 +107,12 @@ pub fn bind_js_function_to_number(mut cx: FunctionContext) -> JsResult<JsValue>
     f.bind(&mut cx).this(42)?.call()
 }
 
+pub fn bind_js_function_to_value(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let this = cx.argument::<JsValue>(1)?;
+    f.bind(&mut cx).this(this)?.call()
+}
+
 fn get_math_max<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsFunction> {
     let math: Handle<JsObject> = cx.global("Math")?;
     let max: Handle<JsFunction> = math.get(cx, "max")?;
@@ -248,6 +276,17 @@ pub fn execute_scoped(mut cx: FunctionContext) -> JsResult<JsNumber> {
     Ok(cx.number(i))
 }
 
+pub fn execute_scoped_batched(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let every = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    let values = cx.execute_scoped_batched(every, 100, |cx, i| {
+        let n = cx.number(i as f64);
+
+        n.value(cx)
+    });
+
+    Ok(cx.number(values.iter().sum::<f64>()))
+}
+
 pub fn compute_scoped(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let mut i = cx.number(0);
     for _ in 1..100 {
@@ -286,6 +325,31 @@ pub fn call_and_catch(mut cx: FunctionContext) -> JsResult<JsValue> {
         .unwrap_or_else(|err| err))
 }
 
+pub fn call_and_catch_unwind(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f: Handle<JsFunction> = cx.argument(0)?;
+    let should_panic = cx.argument::<JsBoolean>(1)?.value(&mut cx);
+
+    let res = cx.try_catch_unwind(|cx| {
+        let res = f.bind(cx).call::<Handle<JsValue>>();
+
+        if should_panic {
+            panic!("plugin misbehaved");
+        }
+
+        res
+    });
+
+    match res {
+        Ok(v) => Ok(v),
+        Err(CaughtError::Throw(err)) => Ok(err),
+        Err(CaughtError::Panic(panic)) => {
+            let msg = panic.downcast_ref::<&str>().copied().unwrap_or("unknown");
+
+            Ok(cx.string(format!("panic: {msg}")).upcast())
+        }
+    }
+}
+
 pub fn get_number_or_default(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let n = cx
         .try_catch(|cx| Ok(cx.argument::<JsNumber>(0)?.value(cx)))
@@ -373,3 +437,83 @@ pub fn caller_with_drop_callback(mut cx: FunctionContext) -> JsResult<JsFunction
         callback.f.to_inner(&mut cx).call(&mut cx, this, args)
     })
 }
+
+pub fn call_with_rest_arguments(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let args = cx.argument_slice(1, usize::MAX);
+    let this = cx.undefined();
+
+    f.call(&mut cx, this, args)
+}
+
+pub fn return_argument_slice(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let start = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    let end = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let args = cx.argument_slice(start, end);
+    let array = JsArray::new(&mut cx, args.len());
+
+    for (i, arg) in args.into_iter().enumerate() {
+        array.prop(&mut cx, i as u32).set(arg)?;
+    }
+
+    Ok(array)
+}
+
+pub fn count_arguments_vec(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let n = cx.arguments_vec().len();
+
+    Ok(cx.number(n as f64))
+}
+
+pub fn apply_js_function(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let this = cx.argument::<JsValue>(1)?;
+    let args = cx.argument::<JsArray>(2)?;
+
+    f.apply(&mut cx, this, args)
+}
+
+pub fn make_counter_mut(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let mut count = 0;
+
+    JsFunction::new_mut(&mut cx, move |mut cx| {
+        count += 1;
+
+        Ok(cx.number(count))
+    })
+}
+
+pub fn make_reentrant_mut(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let mut calls = 0;
+
+    JsFunction::new_mut(&mut cx, move |mut cx| {
+        let callback: Handle<JsFunction> = cx.argument(0)?;
+
+        calls += 1;
+        callback.bind(&mut cx).exec()?;
+
+        Ok(cx.number(calls))
+    })
+}
+
+pub fn is_exception_pending_after_call(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let f: Handle<JsFunction> = cx.argument(0)?;
+    let before = cx.has_pending_exception();
+    let mut during = false;
+    let _ = cx.try_catch(|cx| {
+        let res = f.bind(cx).exec();
+
+        during = cx.has_pending_exception();
+        res
+    });
+    let after = cx.has_pending_exception();
+    let result = cx.empty_array();
+
+    for (i, pending) in [before, during, after].into_iter().enumerate() {
+        let pending = cx.boolean(pending);
+
+        result.set(&mut cx, i as u32, pending)?;
+    }
+
+    Ok(result)
+}
@@ -1,4 +1,8 @@
-use std::future::Future;
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 use neon::{
     prelude::*,
@@ -79,6 +83,91 @@ pub fn lazy_async_sum(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
+// Accepts a function returning a promise. Resolves with `resolved: ${value}` or
+// `rejected: ${reason}`.
+// Purpose: Test that the rejection reason is available from `JsPromise::to_future`
+pub fn lazy_async_settled(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let settled = cx
+        .argument::<JsFunction>(0)?
+        .bind(&mut cx)
+        .call::<Handle<JsPromise>>()?
+        .to_future(&mut cx, |mut cx, result| match result {
+            Ok(value) => Ok(Ok(value.to_string(&mut cx)?.value(&mut cx))),
+            Err(reason) => Ok(Err(reason.to_string(&mut cx)?.value(&mut cx))),
+        })?;
+
+    let (deferred, promise) = cx.promise();
+    let channel = cx.channel();
+    let runtime = runtime(&mut cx)?;
+
+    runtime.spawn(async move {
+        let result = settled.await;
+
+        deferred.settle_with(&channel, move |mut cx| {
+            let message = match result.or_throw(&mut cx)? {
+                Ok(value) => format!("resolved: {value}"),
+                Err(reason) => format!("rejected: {reason}"),
+            };
+
+            Ok(cx.string(message))
+        });
+    });
+
+    Ok(promise)
+}
+
+// Accepts two numbers and resolves with their quotient, rejecting when dividing by zero.
+// Purpose: Test `JsPromise::from_future`
+pub fn from_future_div(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let a = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let b = cx.argument::<JsNumber>(1)?.value(&mut cx);
+
+    JsPromise::from_future(
+        &mut cx,
+        async move { (b != 0.0).then(|| a / b) },
+        |mut cx, n| match n {
+            Some(n) => Ok(cx.number(n)),
+            None => cx.throw_range_error("Divide by zero"),
+        },
+    )
+}
+
+// Replaces the global executor with a handle to the same runtime
+// Purpose: Test `neon::global_executor` and `neon::replace_global_executor`
+pub fn replace_global_executor(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let handle = runtime(&mut cx)?.handle().clone();
+    let had_executor = neon::global_executor(&mut cx).is_some();
+    let previous = neon::replace_global_executor(&mut cx, handle);
+
+    Ok(cx.boolean(had_executor && previous.is_some()))
+}
+
+static SPAWN_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+// A custom executor that counts spawned futures and delegates to tokio
+struct CountingExecutor(tokio::runtime::Handle);
+
+impl neon::Executor for CountingExecutor {
+    fn spawn(&self, fut: Pin<Box<dyn Future<Output = ()> + Send + 'static>>) {
+        SPAWN_COUNT.fetch_add(1, Ordering::SeqCst);
+        self.0.spawn(fut);
+    }
+}
+
+// Purpose: Test that a custom `neon::Executor` drives exported async functions
+pub fn use_counting_executor(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let handle = runtime(&mut cx)?.handle().clone();
+
+    neon::replace_global_executor(&mut cx, CountingExecutor(handle));
+
+    Ok(cx.undefined())
+}
+
+#[neon::export]
+fn executor_spawn_count() -> f64 {
+    SPAWN_COUNT.load(Ordering::SeqCst) as f64
+}
+
 #[neon::export]
 async fn async_fn_add(a: f64, b: f64) -> f64 {
     a + b
@@ -0,0 +1,68 @@
+use neon::{prelude::*, types::JsMap};
+
+pub fn create_map_from_pairs(mut cx: FunctionContext) -> JsResult<JsMap> {
+    let pairs = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+    let map = JsMap::new(&mut cx)?;
+
+    for pair in pairs {
+        let pair = pair.downcast_or_throw::<JsArray, _>(&mut cx)?;
+        let key: Handle<JsValue> = pair.prop(&mut cx, 0).get()?;
+        let value: Handle<JsValue> = pair.prop(&mut cx, 1).get()?;
+
+        map.set(&mut cx, key, value)?;
+    }
+
+    Ok(map)
+}
+
+pub fn map_get(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let map = cx.argument::<JsMap>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+
+    map.get(&mut cx, key)
+}
+
+pub fn map_has(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let map = cx.argument::<JsMap>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let has = map.has(&mut cx, key)?;
+
+    Ok(cx.boolean(has))
+}
+
+pub fn map_delete(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let map = cx.argument::<JsMap>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let deleted = map.delete(&mut cx, key)?;
+
+    Ok(cx.boolean(deleted))
+}
+
+pub fn map_size(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let map = cx.argument::<JsMap>(0)?;
+    let size = map.size(&mut cx)?;
+
+    Ok(cx.number(size))
+}
+
+pub fn map_entries(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let map = cx.argument::<JsMap>(0)?;
+    let mut pairs = vec![];
+    let mut entries = map.entries(&mut cx)?;
+
+    while let Some(entry) = entries.next() {
+        let (key, value) = entry?;
+        let pair = JsArray::from_slice(entries.cx(), &[key, value])?;
+
+        pairs.push(pair);
+    }
+
+    JsArray::from_slice(&mut cx, &pairs)
+}
+
+pub fn is_map(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let value = cx.argument::<JsValue>(0)?;
+    let is_map = value.is_a::<JsMap, _>(&mut cx);
+
+    Ok(cx.boolean(is_map))
+}
@@ -39,3 +39,32 @@ pub fn accept_and_return_negative_js_number(mut cx: FunctionContext) -> JsResult
     let number: Handle<JsNumber> = cx.argument(0)?;
     Ok(number)
 }
+
+pub fn number_as_i64_checked(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let number: Handle<JsNumber> = cx.argument(0)?;
+
+    match number.as_i64_checked(&mut cx) {
+        Some(n) => Ok(cx.string(n.to_string()).upcast()),
+        None => Ok(cx.null().upcast()),
+    }
+}
+
+pub fn number_is_safe_integer(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let number: Handle<JsNumber> = cx.argument(0)?;
+    let is_safe = number.is_safe_integer(&mut cx);
+
+    Ok(cx.boolean(is_safe))
+}
+
+pub fn parse_int_with_radix(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let s = cx.argument::<JsString>(0)?.value(&mut cx);
+    let radix = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+
+    cx.parse_int(&s, radix)
+}
+
+pub fn parse_float_from_string(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let s = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.parse_float(&s)
+}
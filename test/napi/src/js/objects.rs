@@ -1,11 +1,19 @@
 use std::borrow::Cow;
 
-use neon::{prelude::*, types::buffer::TypedArray};
+use neon::{
+    object::{PropertyDescriptor, PropertyFilter},
+    prelude::*,
+    types::{buffer::TypedArray, JsProxy},
+};
 
 pub fn return_js_global_object(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(cx.global_object())
 }
 
+pub fn return_js_global_this(mut cx: FunctionContext) -> JsResult<JsObject> {
+    cx.global_this()
+}
+
 pub fn return_js_object(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(cx.empty_object())
 }
@@ -41,6 +49,138 @@ pub fn freeze_js_object(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     }
 }
 
+pub fn set_after_freeze(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.empty_object();
+
+    obj.prop(&mut cx, "x").set(1)?;
+    obj.freeze(&mut cx)?;
+    obj.prop(&mut cx, "x").set(2)?;
+    obj.prop(&mut cx, "y").set(3)?;
+
+    Ok(obj)
+}
+
+// Defines a property from a descriptor object with the same shape as the argument to
+// `Object.defineProperty`
+pub fn define_property_from(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let obj: Handle<JsObject> = cx.argument(0)?;
+    let key: Handle<JsValue> = cx.argument(1)?;
+    let desc: Handle<JsObject> = cx.argument(2)?;
+    let mut descriptor = PropertyDescriptor::new();
+
+    if let Some(writable) = desc.prop(&mut cx, "writable").get()? {
+        descriptor = descriptor.writable(writable);
+    }
+
+    if let Some(enumerable) = desc.prop(&mut cx, "enumerable").get()? {
+        descriptor = descriptor.enumerable(enumerable);
+    }
+
+    if let Some(configurable) = desc.prop(&mut cx, "configurable").get()? {
+        descriptor = descriptor.configurable(configurable);
+    }
+
+    if desc.has_own(&mut cx, "value")? {
+        let value: Handle<JsValue> = desc.prop(&mut cx, "value").get()?;
+        descriptor = descriptor.value(value);
+    }
+
+    if let Some(get) = desc.prop(&mut cx, "get").get()? {
+        descriptor = descriptor.get(get);
+    }
+
+    if let Some(set) = desc.prop(&mut cx, "set").get()? {
+        descriptor = descriptor.set(set);
+    }
+
+    obj.define_property(&mut cx, key, descriptor)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn read_options_with_defaults(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let opts: Handle<JsObject> = cx.argument(0)?;
+    let host: String = opts
+        .prop(&mut cx, "host")
+        .get_or_else(|_| "localhost".into())?;
+    let port: f64 = opts.prop(&mut cx, "port").get_or(8080.0)?;
+    let verbose: bool = opts.prop(&mut cx, "verbose").get_or_default()?;
+    let result = cx.empty_object();
+
+    result
+        .prop(&mut cx, "host")
+        .set(host)?
+        .prop("port")
+        .set(port)?
+        .prop("verbose")
+        .set(verbose)?;
+
+    Ok(result)
+}
+
+pub fn own_property_keys(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let obj: Handle<JsObject> = cx.argument(0)?;
+    let opts: Handle<JsObject> = cx.argument(1)?;
+    let mut filter = PropertyFilter::new();
+
+    if opts.prop(&mut cx, "enumerable").get::<Option<bool>>()? == Some(true) {
+        filter = filter.enumerable();
+    }
+
+    if opts.prop(&mut cx, "writable").get::<Option<bool>>()? == Some(true) {
+        filter = filter.writable();
+    }
+
+    if opts.prop(&mut cx, "configurable").get::<Option<bool>>()? == Some(true) {
+        filter = filter.configurable();
+    }
+
+    if opts.prop(&mut cx, "skipStrings").get::<Option<bool>>()? == Some(true) {
+        filter = filter.skip_strings();
+    }
+
+    if opts.prop(&mut cx, "skipSymbols").get::<Option<bool>>()? == Some(true) {
+        filter = filter.skip_symbols();
+    }
+
+    obj.own_property_keys(&mut cx, filter)
+}
+
+pub fn read_only_proxy(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let target: Handle<JsObject> = cx.argument(0)?;
+    let handler = cx.empty_object();
+    let set = JsFunction::new(&mut cx, |mut cx| {
+        cx.throw_type_error::<_, Handle<JsValue>>("object is read-only")
+    })?;
+
+    handler.prop(&mut cx, "set").set(set)?;
+
+    JsProxy::new(&mut cx, target, handler)
+}
+
+pub fn get_prototype_of(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj: Handle<JsObject> = cx.argument(0)?;
+
+    neon::reflect::get_prototype_of(&mut cx, obj)
+}
+
+pub fn set_prototype_of(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj: Handle<JsObject> = cx.argument(0)?;
+    let proto: Handle<JsValue> = cx.argument(1)?;
+
+    neon::reflect::set_prototype_of(&mut cx, obj, proto)?;
+
+    Ok(obj)
+}
+
+pub fn instance_of(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let value: Handle<JsValue> = cx.argument(0)?;
+    let constructor: Handle<JsFunction> = cx.argument(1)?;
+    let result = neon::reflect::instance_of(&mut cx, value, constructor)?;
+
+    Ok(cx.boolean(result))
+}
+
 pub fn seal_js_object(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     let obj: Handle<JsObject> = cx.argument::<JsObject>(0)?;
     match obj.seal(&mut cx) {
@@ -117,8 +257,93 @@ pub fn call_methods_with_prop(mut cx: FunctionContext) -> JsResult<JsString> {
     obj.prop(&mut cx, "toString").bind()?.call()
 }
 
+pub fn call_method_with_tuple(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj: Handle<JsObject> = cx.argument::<JsObject>(0)?;
+    obj.call_method(&mut cx, "setName", ("Wonder Woman",))?;
+    obj.call_method(&mut cx, "toString", ())
+}
+
+pub fn call_method_with_slice(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj: Handle<JsObject> = cx.argument::<JsObject>(0)?;
+    let args = [cx.number(1), cx.number(2), cx.number(3)];
+    obj.call_method(&mut cx, "sum", &args[..])
+}
+
 pub fn call_non_method_with_prop(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     let obj: Handle<JsObject> = cx.argument::<JsObject>(0)?;
     obj.prop(&mut cx, "number").bind()?.exec()?;
     Ok(cx.undefined())
 }
+
+pub fn delete_property(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj: Handle<JsObject> = cx.argument(0)?;
+    let key: Handle<JsValue> = cx.argument(1)?;
+
+    let deleted = if let Ok(index) = key.downcast::<JsNumber, _>(&mut cx) {
+        let index = index.value(&mut cx) as u32;
+        obj.delete(&mut cx, index)?
+    } else {
+        let key = key
+            .downcast_or_throw::<JsString, _>(&mut cx)?
+            .value(&mut cx);
+        obj.delete(&mut cx, key.as_str())?
+    };
+
+    Ok(cx.boolean(deleted))
+}
+
+pub fn has_property(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let obj: Handle<JsObject> = cx.argument(0)?;
+    let key: Handle<JsValue> = cx.argument(1)?;
+
+    let (has, has_own) = if let Ok(index) = key.downcast::<JsNumber, _>(&mut cx) {
+        let index = index.value(&mut cx) as u32;
+        (obj.has(&mut cx, index)?, obj.has_own(&mut cx, index)?)
+    } else if let Ok(key) = key.downcast::<JsString, _>(&mut cx) {
+        let key = key.value(&mut cx);
+        (
+            obj.has(&mut cx, key.as_str())?,
+            obj.has_own(&mut cx, key.as_str())?,
+        )
+    } else {
+        (obj.has(&mut cx, key)?, obj.has_own(&mut cx, key)?)
+    };
+
+    let result = cx.empty_array();
+    result.prop(&mut cx, 0).set(has)?;
+    result.prop(&mut cx, 1).set(has_own)?;
+
+    Ok(result)
+}
+
+pub fn get_property_with_symbol(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj: Handle<JsObject> = cx.argument(0)?;
+    let sym: Handle<JsValue> = cx.argument(1)?;
+
+    obj.prop(&mut cx, sym).get()
+}
+
+pub fn set_property_with_symbol(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj: Handle<JsObject> = cx.argument(0)?;
+    let sym: Handle<JsValue> = cx.argument(1)?;
+    let value: Handle<JsValue> = cx.argument(2)?;
+
+    obj.prop(&mut cx, sym).set(value)?;
+
+    Ok(obj)
+}
+
+pub fn get_symbol_iterator(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj: Handle<JsObject> = cx.argument(0)?;
+    let symbol: Handle<JsFunction> = cx.global("Symbol")?;
+    let iterator: Handle<JsValue> = symbol.prop(&mut cx, "iterator").get()?;
+
+    obj.prop(&mut cx, iterator).get()
+}
+
+pub fn try_root_and_unwrap(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let root = obj.try_root(&mut cx)?;
+
+    Ok(root.into_inner(&mut cx))
+}
@@ -0,0 +1,43 @@
+use neon::{prelude::*, types::JsRegExp};
+
+pub fn create_regexp(mut cx: FunctionContext) -> JsResult<JsRegExp> {
+    let pattern = cx.argument::<JsString>(0)?.value(&mut cx);
+    let flags = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    JsRegExp::new(&mut cx, &pattern, &flags)
+}
+
+pub fn regexp_source_and_flags(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let re = cx.argument::<JsRegExp>(0)?;
+    let source = re.source(&mut cx)?;
+    let flags = re.flags(&mut cx)?;
+    let source = cx.string(source);
+    let flags = cx.string(flags);
+
+    JsArray::from_slice(&mut cx, &[source, flags])
+}
+
+pub fn regexp_test(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let re = cx.argument::<JsRegExp>(0)?;
+    let input = cx.argument::<JsString>(1)?;
+    let matched = re.test(&mut cx, input)?;
+
+    Ok(cx.boolean(matched))
+}
+
+pub fn regexp_exec(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let re = cx.argument::<JsRegExp>(0)?;
+    let input = cx.argument::<JsString>(1)?;
+
+    match re.exec(&mut cx, input)? {
+        Some(result) => Ok(result.upcast()),
+        None => Ok(cx.null().upcast()),
+    }
+}
+
+pub fn is_regexp(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let value = cx.argument::<JsValue>(0)?;
+    let is_regexp = value.is_a::<JsRegExp, _>(&mut cx);
+
+    Ok(cx.boolean(is_regexp))
+}
@@ -0,0 +1,49 @@
+use neon::{prelude::*, types::JsSet};
+
+pub fn create_set_from_array(mut cx: FunctionContext) -> JsResult<JsSet> {
+    let values = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+    let set = JsSet::new(&mut cx)?;
+
+    for value in values {
+        set.add(&mut cx, value)?;
+    }
+
+    Ok(set)
+}
+
+pub fn set_has(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let set = cx.argument::<JsSet>(0)?;
+    let value = cx.argument::<JsValue>(1)?;
+    let has = set.has(&mut cx, value)?;
+
+    Ok(cx.boolean(has))
+}
+
+pub fn set_delete(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let set = cx.argument::<JsSet>(0)?;
+    let value = cx.argument::<JsValue>(1)?;
+    let deleted = set.delete(&mut cx, value)?;
+
+    Ok(cx.boolean(deleted))
+}
+
+pub fn set_size(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let set = cx.argument::<JsSet>(0)?;
+    let size = set.size(&mut cx)?;
+
+    Ok(cx.number(size))
+}
+
+pub fn set_values(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let set = cx.argument::<JsSet>(0)?;
+    let values = set.values(&mut cx)?.collect::<NeonResult<Vec<_>>>()?;
+
+    JsArray::from_slice(&mut cx, &values)
+}
+
+pub fn is_set(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let value = cx.argument::<JsValue>(0)?;
+    let is_set = value.is_a::<JsSet, _>(&mut cx);
+
+    Ok(cx.boolean(is_set))
+}
@@ -1,4 +1,7 @@
-use neon::{prelude::*, reflect::eval};
+use neon::{
+    prelude::*,
+    reflect::{eval, run_script},
+};
 
 pub fn return_js_string(mut cx: FunctionContext) -> JsResult<JsString> {
     Ok(cx.string("hello node"))
@@ -23,3 +26,49 @@ pub fn run_string_as_script(mut cx: FunctionContext) -> JsResult<JsValue> {
     let string_script = cx.argument::<JsString>(0)?;
     eval(&mut cx, string_script)
 }
+
+pub fn run_string_as_named_script(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let string_script = cx.argument::<JsString>(0)?;
+    let name = cx.argument::<JsString>(1)?.value(&mut cx);
+    run_script(&mut cx, string_script, &name)
+}
+
+pub fn truncate_string(mut cx: FunctionContext) -> JsResult<JsString> {
+    let value = cx.argument::<JsString>(0)?.value(&mut cx);
+    let max_bytes = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    JsString::try_new_truncated(&mut cx, value, max_bytes, "…").or_throw(&mut cx)
+}
+
+pub fn return_string_value_lossy(mut cx: FunctionContext) -> JsResult<JsString> {
+    let value = cx.argument::<JsString>(0)?.value_lossy(&mut cx);
+    Ok(cx.string(value))
+}
+
+pub fn roundtrip_string_utf16(mut cx: FunctionContext) -> JsResult<JsString> {
+    let value = cx.argument::<JsString>(0)?.to_utf16(&mut cx);
+    cx.string_utf16(&value).or_throw(&mut cx)
+}
+
+pub fn join_strings_value_into(mut cx: FunctionContext) -> JsResult<JsString> {
+    let strings = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+    let mut buf = String::with_capacity(64);
+    let mut joined = String::new();
+
+    for s in strings {
+        s.downcast_or_throw::<JsString, _>(&mut cx)?
+            .value_into(&mut cx, &mut buf);
+        joined.push_str(&buf);
+        joined.push('|');
+    }
+
+    Ok(cx.string(joined))
+}
+
+pub fn cstring_byte_len(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let s = cx.argument::<JsString>(0)?;
+    let s = s
+        .to_cstring(&mut cx)
+        .or_else(|err| cx.throw_type_error(err.to_string()))?;
+
+    Ok(cx.number(s.as_bytes_with_nul().len() as f64))
+}
@@ -0,0 +1,44 @@
+use neon::{prelude::*, types::JsSymbol};
+
+pub fn create_symbol(mut cx: FunctionContext) -> JsResult<JsSymbol> {
+    let description = cx.argument_opt(0);
+    let description = match description {
+        Some(v) if !v.is_a::<JsUndefined, _>(&mut cx) => {
+            Some(v.downcast_or_throw::<JsString, _>(&mut cx)?.value(&mut cx))
+        }
+        _ => None,
+    };
+
+    Ok(JsSymbol::new(&mut cx, description.as_deref()))
+}
+
+pub fn symbol_for(mut cx: FunctionContext) -> JsResult<JsSymbol> {
+    let key = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsSymbol::for_key(&mut cx, &key)
+}
+
+pub fn symbol_description(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let symbol = cx.argument::<JsSymbol>(0)?;
+
+    match symbol.description(&mut cx)? {
+        Some(description) => Ok(cx.string(description).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn is_symbol(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let value = cx.argument::<JsValue>(0)?;
+    let is_symbol = value.is_a::<JsSymbol, _>(&mut cx);
+
+    Ok(cx.boolean(is_symbol))
+}
+
+pub fn set_symbol_property(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.empty_object();
+    let key = JsSymbol::for_key(&mut cx, "neon.key")?;
+
+    obj.prop(&mut cx, key).set("value")?;
+
+    Ok(obj)
+}
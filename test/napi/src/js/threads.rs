@@ -1,6 +1,14 @@
-use std::{cell::RefCell, sync::Arc, time::Duration};
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use neon::{
+    event::{CancelToken, TaskBuilder},
     prelude::*,
     types::{buffer::TypedArray, extract::Error},
 };
@@ -24,6 +32,19 @@ pub fn thread_callback(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     Ok(cx.undefined())
 }
 
+pub fn channel_call_root(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let channel = cx.channel();
+
+    std::thread::spawn(move || {
+        let message = String::from("hello");
+
+        channel.call_root(callback, (message, 42.0))
+    });
+
+    Ok(cx.undefined())
+}
+
 pub fn multi_threaded_callback(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
     let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
@@ -132,6 +153,24 @@ pub fn leak_channel(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     Ok(cx.undefined())
 }
 
+pub fn channel_reference_state(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let mut channel = cx.channel();
+    let states = [
+        channel.is_referenced(),
+        channel.mark_unreferenced(&mut cx),
+        channel.is_referenced(),
+        channel.mark_unreferenced(&mut cx),
+        channel.mark_referenced(&mut cx),
+        channel.has_ref(),
+        channel.mark_referenced(&mut cx),
+        channel.mark_unreferenced(&mut cx),
+        channel.has_ref(),
+    ];
+    let states = states.map(|state| cx.boolean(state));
+
+    JsArray::from_slice(&mut cx, &states)
+}
+
 pub fn drop_global_queue(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     struct Wrapper {
         callback: Option<Root<JsFunction>>,
@@ -214,6 +253,38 @@ pub fn channel_join(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     Ok(cx.undefined())
 }
 
+pub fn channel_send_and_wait(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let get_message = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+    let channel = cx.channel();
+
+    std::thread::spawn(move || {
+        // Block this thread until the message has been fetched
+        let message: String = channel
+            .send_and_wait(move |mut cx| get_message.into_inner(&mut cx).bind(&mut cx).call())
+            .unwrap();
+
+        let response = format!("Received: {message}");
+
+        channel.send(move |mut cx| {
+            callback
+                .into_inner(&mut cx)
+                .bind(&mut cx)
+                .arg(response)?
+                .exec()
+        });
+    });
+
+    Ok(cx.undefined())
+}
+
+pub fn channel_send_and_wait_on_js_thread(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let channel = cx.channel();
+    let _ = channel.send_and_wait(|_| Ok(()));
+
+    Ok(cx.undefined())
+}
+
 pub fn sum(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let nums = cx.argument::<JsTypedArray<f64>>(0)?.as_slice(&cx).to_vec();
 
@@ -418,6 +489,78 @@ pub fn task_panic_throw_promise(mut cx: FunctionContext) -> JsResult<JsPromise>
     Ok(promise)
 }
 
+// Parses a number in one task and squares it in a second task chained from the first
+pub fn task_chain_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let input = cx.argument::<JsString>(0)?.value(&mut cx);
+    let promise = cx
+        .task(move || input.parse::<f64>().ok())
+        .promise(move |mut cx, n| match n {
+            Some(n) => Ok(cx.task(move || n * n).promise(|mut cx, n| Ok(cx.number(n)))),
+            None => cx.throw_type_error("expected a number"),
+        });
+
+    Ok(promise)
+}
+
+pub fn task_cancel_before_start(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let (task, token) = TaskBuilder::with_cancellation(&mut cx, |_: &CancelToken| ());
+
+    token.cancel();
+
+    Ok(task.promise(|mut cx, _| Ok(cx.undefined())))
+}
+
+pub struct CancellableTask {
+    token: CancelToken,
+    started: Arc<AtomicBool>,
+}
+
+impl Finalize for CancellableTask {}
+
+// Returns `[promise, task]`. The task waits until it is cancelled with `cancel_task`
+// and resolves with the number of times it checked for cancellation.
+pub fn task_wait_for_cancel(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let started = Arc::new(AtomicBool::new(false));
+    let (task, token) = TaskBuilder::with_cancellation(&mut cx, {
+        let started = started.clone();
+
+        move |token: &CancelToken| {
+            let mut checks = 0;
+
+            started.store(true, Ordering::SeqCst);
+
+            while !token.is_cancelled() {
+                checks += 1;
+                std::thread::sleep(Duration::from_millis(1));
+            }
+
+            checks
+        }
+    });
+
+    let promise = task.promise(|mut cx, checks| Ok(cx.number(checks)));
+    let task = cx.boxed(CancellableTask { token, started });
+    let result = cx.empty_array();
+
+    result.prop(&mut cx, 0).set(promise)?;
+    result.prop(&mut cx, 1).set(task)?;
+
+    Ok(result)
+}
+
+pub fn task_started(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let task = cx.argument::<JsBox<CancellableTask>>(0)?;
+    let started = task.started.load(Ordering::SeqCst);
+
+    Ok(cx.boolean(started))
+}
+
+pub fn cancel_task(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    cx.argument::<JsBox<CancellableTask>>(0)?.token.cancel();
+
+    Ok(cx.undefined())
+}
+
 pub fn deferred_settle_with_throw(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let msg = cx.argument::<JsString>(0)?.value(&mut cx);
     let (deferred, promise) = cx.promise();
@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use neon::{
     prelude::*,
     types::buffer::{Binary, BorrowError, TypedArray},
@@ -111,6 +113,12 @@ pub fn return_buffer(mut cx: FunctionContext) -> JsResult<JsBuffer> {
     Ok(b)
 }
 
+pub fn return_buffer_from_slice(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    let data = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsBuffer::from_slice(&mut cx, data.as_bytes())
+}
+
 pub fn return_external_buffer(mut cx: FunctionContext) -> JsResult<JsBuffer> {
     let data = cx.argument::<JsString>(0)?.value(&mut cx);
     let buf = JsBuffer::external(&mut cx, data.into_bytes());
@@ -125,6 +133,38 @@ pub fn return_external_array_buffer(mut cx: FunctionContext) -> JsResult<JsArray
     Ok(buf)
 }
 
+pub fn return_array_buffer_from_vec(mut cx: FunctionContext) -> JsResult<JsArrayBuffer> {
+    let data = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsArrayBuffer::from_vec(&mut cx, data.into_bytes())
+}
+
+static EXTERNAL_DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+struct DropCounter(Vec<u8>);
+
+impl AsMut<[u8]> for DropCounter {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        EXTERNAL_DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+pub fn return_counted_external_array_buffer(mut cx: FunctionContext) -> JsResult<JsArrayBuffer> {
+    let len = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+
+    Ok(JsArrayBuffer::external(&mut cx, DropCounter(vec![0; len])))
+}
+
+pub fn external_drop_count(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(cx.number(EXTERNAL_DROP_COUNT.load(Ordering::SeqCst) as f64))
+}
+
 pub fn return_int8array_from_arraybuffer(mut cx: FunctionContext) -> JsResult<JsInt8Array> {
     let buf = cx.argument::<JsArrayBuffer>(0)?;
     JsInt8Array::from_buffer(&mut cx, buf)
@@ -169,6 +209,26 @@ pub fn return_int32array_from_slice(mut cx: FunctionContext) -> JsResult<JsInt32
     Ok(a)
 }
 
+pub fn float64array_to_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let a: Handle<JsFloat64Array> = cx.argument(0)?;
+    let values = a.to_vec(&mut cx)?;
+    let arr = cx.empty_array();
+
+    for (i, v) in values.into_iter().enumerate() {
+        arr.prop(&mut cx, i as u32).set(v)?;
+    }
+
+    Ok(arr)
+}
+
+pub fn uint32array_subarray(mut cx: FunctionContext) -> JsResult<JsUint32Array> {
+    let a: Handle<JsUint32Array> = cx.argument(0)?;
+    let begin = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let end = cx.argument::<JsNumber>(2)?.value(&mut cx) as usize;
+
+    a.subarray(&mut cx, begin, end)
+}
+
 pub fn return_uint32array_from_arraybuffer_region(
     mut cx: FunctionContext,
 ) -> JsResult<JsUint32Array> {
@@ -216,6 +276,27 @@ where
     Ok(obj)
 }
 
+pub fn detach_array_buffer(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let buf = cx.argument::<JsArrayBuffer>(0)?;
+    let view = cx.argument::<JsUint8Array>(1)?;
+    let len = buf.detach(&mut cx)?;
+    let lengths = [len, buf.as_slice(&cx).len(), view.as_slice(&cx).len()];
+    let result = cx.empty_array();
+
+    for (i, len) in lengths.into_iter().enumerate() {
+        result.prop(&mut cx, i as u32).set(len as f64)?;
+    }
+
+    Ok(result)
+}
+
+pub fn is_array_buffer_detached(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let buf = cx.argument::<JsArrayBuffer>(0)?;
+    let detached = buf.is_detached(&mut cx);
+
+    Ok(cx.boolean(detached))
+}
+
 fn detach_and_then<'cx, F>(mut cx: FunctionContext<'cx>, f: F) -> JsResult<'cx, JsObject>
 where
     F: FnOnce(
@@ -72,3 +72,22 @@ pub fn strict_equals(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     let eq = v1.strict_equals(&mut cx, v2);
     Ok(cx.boolean(eq))
 }
+
+pub fn loose_equals(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let v1: Handle<JsValue> = cx.argument(0)?;
+    let v2: Handle<JsValue> = cx.argument(1)?;
+    let eq = v1.loose_equals(&mut cx, v2)?;
+    Ok(cx.boolean(eq))
+}
+
+pub fn type_of(mut cx: FunctionContext) -> JsResult<JsString> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let type_of = val.type_of(&mut cx);
+    Ok(cx.string(type_of))
+}
+
+pub fn class_name(mut cx: FunctionContext) -> JsResult<JsString> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let class_name = val.class_name(&mut cx)?;
+    Ok(cx.string(class_name))
+}
@@ -1,4 +1,12 @@
-use std::{convert::TryFrom, sync::Mutex, thread, time::Duration};
+use std::{
+    convert::TryFrom,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
 
 use once_cell::sync::{Lazy, OnceCell};
 
@@ -18,6 +26,30 @@ pub fn get_and_replace(mut cx: FunctionContext) -> JsResult<JsValue> {
     })
 }
 
+pub fn try_get_and_replace(mut cx: FunctionContext) -> JsResult<JsValue> {
+    static OBJECT: Lazy<Mutex<Option<Root<JsObject>>>> = Lazy::new(Default::default);
+
+    let mut global = OBJECT.lock().unwrap_or_else(|err| err.into_inner());
+    let next = cx.argument::<JsObject>(0)?.root(&mut cx);
+
+    let previous = match global.replace(next) {
+        None => return Ok(cx.undefined().upcast()),
+        Some(previous) => previous,
+    };
+
+    match previous.try_into_inner(&mut cx) {
+        Ok(previous) => Ok(previous.upcast()),
+        // Created by a different module instance; put it back and drop `next` instead
+        Err(previous) => {
+            if let Some(next) = global.replace(previous) {
+                next.drop(&mut cx);
+            }
+
+            Ok(cx.null().upcast())
+        }
+    }
+}
+
 pub fn get_or_init(mut cx: FunctionContext) -> JsResult<JsObject> {
     static OBJECT: OnceCell<Root<JsObject>> = OnceCell::new();
 
@@ -73,6 +105,46 @@ pub fn get_reentrant_value(mut cx: FunctionContext) -> JsResult<JsValue> {
     }
 }
 
+static LOCAL_MESSAGE: LocalKey<Mutex<Option<String>>> = LocalKey::new();
+
+pub fn replace_local_message(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let message = cx.argument::<JsString>(0)?.value(&mut cx);
+    let previous = LOCAL_MESSAGE
+        .get_or_init_default(&mut cx)
+        .lock()
+        .unwrap()
+        .replace(message);
+
+    match previous {
+        Some(previous) => Ok(cx.string(previous).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+struct InstanceMessage(String);
+
+pub fn set_instance_message(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let message = cx.argument::<JsString>(0)?.value(&mut cx);
+    cx.set_instance_data(InstanceMessage(message));
+    Ok(cx.undefined())
+}
+
+pub fn get_instance_message(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let message = cx
+        .instance_data::<InstanceMessage>()
+        .map(|InstanceMessage(message)| message.clone());
+
+    match message {
+        Some(message) => Ok(cx.string(message).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn get_instance_data_as_string(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let found = cx.instance_data::<String>().is_some();
+    Ok(cx.boolean(found))
+}
+
 static GLOBAL_OBJECT: LocalKey<Root<JsObject>> = LocalKey::new();
 
 pub fn stash_global_object(mut cx: FunctionContext) -> JsResult<JsUndefined> {
@@ -123,3 +195,23 @@ pub fn box_channels(mut cx: FunctionContext) -> JsResult<JsBox<Channels>> {
 
     Ok(cx.boxed(channels))
 }
+
+static DROPPED_ON_EXIT: AtomicUsize = AtomicUsize::new(0);
+
+pub struct DropOnExit;
+
+impl Finalize for DropOnExit {}
+
+impl Drop for DropOnExit {
+    fn drop(&mut self) {
+        DROPPED_ON_EXIT.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+pub fn box_with_drop(mut cx: FunctionContext) -> JsResult<JsBox<DropOnExit>> {
+    Ok(JsBox::new_with_drop(&mut cx, DropOnExit))
+}
+
+pub fn box_with_drop_count(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    Ok(cx.number(DROPPED_ON_EXIT.load(Ordering::SeqCst) as f64))
+}
@@ -18,9 +18,13 @@ mod js {
     pub mod extract;
     pub mod functions;
     pub mod futures;
+    pub mod maps;
     pub mod numbers;
     pub mod objects;
+    pub mod regexps;
+    pub mod sets;
     pub mod strings;
+    pub mod symbols;
     pub mod threads;
     pub mod typedarrays;
     pub mod types;
@@ -124,6 +128,12 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("return_length_utf8", return_length_utf8)?;
     cx.export_function("return_length_utf16", return_length_utf16)?;
     cx.export_function("run_string_as_script", run_string_as_script)?;
+    cx.export_function("run_string_as_named_script", run_string_as_named_script)?;
+    cx.export_function("truncate_string", truncate_string)?;
+    cx.export_function("return_string_value_lossy", return_string_value_lossy)?;
+    cx.export_function("roundtrip_string_utf16", roundtrip_string_utf16)?;
+    cx.export_function("cstring_byte_len", cstring_byte_len)?;
+    cx.export_function("join_strings_value_into", join_strings_value_into)?;
 
     cx.export_function("return_js_number", return_js_number)?;
     cx.export_function("return_large_js_number", return_large_js_number)?;
@@ -146,8 +156,22 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         "accept_and_return_negative_js_number",
         accept_and_return_negative_js_number,
     )?;
+    cx.export_function("number_as_i64_checked", number_as_i64_checked)?;
+    cx.export_function("number_is_safe_integer", number_is_safe_integer)?;
+    cx.export_function("parse_int_with_radix", parse_int_with_radix)?;
+    cx.export_function("parse_float_from_string", parse_float_from_string)?;
 
     cx.export_function("return_js_function", return_js_function)?;
+    cx.export_function("return_renamed_js_function", return_renamed_js_function)?;
+    cx.export_function(
+        "return_js_function_with_arity",
+        return_js_function_with_arity,
+    )?;
+    cx.export_function("get_js_function_name", get_js_function_name)?;
+    cx.export_function("call_with_rest_arguments", call_with_rest_arguments)?;
+    cx.export_function("return_argument_slice", return_argument_slice)?;
+    cx.export_function("count_arguments_vec", count_arguments_vec)?;
+    cx.export_function("apply_js_function", apply_js_function)?;
     cx.export_function("call_js_function", call_js_function)?;
     cx.export_function(
         "call_js_function_idiomatically",
@@ -173,6 +197,7 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     )?;
     cx.export_function("bind_js_function_to_object", bind_js_function_to_object)?;
     cx.export_function("bind_js_function_to_number", bind_js_function_to_number)?;
+    cx.export_function("bind_js_function_to_value", bind_js_function_to_value)?;
     cx.export_function(
         "call_js_function_with_zero_args",
         call_js_function_with_zero_args,
@@ -225,6 +250,7 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("require_argument_zero_string", require_argument_zero_string)?;
     cx.export_function("check_string_and_number", check_string_and_number)?;
     cx.export_function("execute_scoped", execute_scoped)?;
+    cx.export_function("execute_scoped_batched", execute_scoped_batched)?;
     cx.export_function("compute_scoped", compute_scoped)?;
     cx.export_function("recompute_scoped", recompute_scoped)?;
 
@@ -232,10 +258,27 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("return_js_array_with_number", return_js_array_with_number)?;
     cx.export_function("return_js_array_with_string", return_js_array_with_string)?;
     cx.export_function("read_js_array", read_js_array)?;
+    cx.export_function("return_js_array_from_slice", return_js_array_from_slice)?;
+    cx.export_function(
+        "return_empty_js_array_from_slice",
+        return_empty_js_array_from_slice,
+    )?;
+    cx.export_function(
+        "find_first_string_in_js_array",
+        find_first_string_in_js_array,
+    )?;
+    cx.export_function("count_js_array_iter", count_js_array_iter)?;
+    cx.export_function("push_js_array", push_js_array)?;
+    cx.export_function("pop_js_array", pop_js_array)?;
+    cx.export_function("splice_js_array", splice_js_array)?;
 
     cx.export_function("to_string", to_string)?;
+    cx.export_function("coerce_to_number", coerce_to_number)?;
+    cx.export_function("coerce_to_string", coerce_to_string)?;
+    cx.export_function("coerce_to_boolean", coerce_to_boolean)?;
 
     cx.export_function("return_js_global_object", return_js_global_object)?;
+    cx.export_function("return_js_global_this", return_js_global_this)?;
     cx.export_function("return_js_object", return_js_object)?;
     cx.export_function("return_js_object_with_number", return_js_object_with_number)?;
     cx.export_function("return_js_object_with_string", return_js_object_with_string)?;
@@ -244,6 +287,15 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         return_js_object_with_mixed_content,
     )?;
     cx.export_function("freeze_js_object", freeze_js_object)?;
+    cx.export_function("set_after_freeze", set_after_freeze)?;
+    cx.export_function("own_property_keys", own_property_keys)?;
+    cx.export_function("read_only_proxy", read_only_proxy)?;
+    cx.export_function("get_prototype_of", get_prototype_of)?;
+    cx.export_function("set_prototype_of", set_prototype_of)?;
+    cx.export_function("instance_of", instance_of)?;
+    cx.export_function("read_options_with_defaults", read_options_with_defaults)?;
+    cx.export_function("define_property_from", define_property_from)?;
+    cx.export_function("try_root_and_unwrap", try_root_and_unwrap)?;
     cx.export_function("seal_js_object", seal_js_object)?;
 
     cx.export_function("return_array_buffer", return_array_buffer)?;
@@ -270,8 +322,15 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("copy_typed_array", copy_typed_array)?;
     cx.export_function("return_uninitialized_buffer", return_uninitialized_buffer)?;
     cx.export_function("return_buffer", return_buffer)?;
+    cx.export_function("return_buffer_from_slice", return_buffer_from_slice)?;
     cx.export_function("return_external_buffer", return_external_buffer)?;
     cx.export_function("return_external_array_buffer", return_external_array_buffer)?;
+    cx.export_function("return_array_buffer_from_vec", return_array_buffer_from_vec)?;
+    cx.export_function(
+        "return_counted_external_array_buffer",
+        return_counted_external_array_buffer,
+    )?;
+    cx.export_function("external_drop_count", external_drop_count)?;
     cx.export_function(
         "return_int8array_from_arraybuffer",
         return_int8array_from_arraybuffer,
@@ -294,11 +353,15 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     )?;
     cx.export_function("return_new_int32array", return_new_int32array)?;
     cx.export_function("return_int32array_from_slice", return_int32array_from_slice)?;
+    cx.export_function("float64array_to_array", float64array_to_array)?;
+    cx.export_function("uint32array_subarray", uint32array_subarray)?;
     cx.export_function(
         "return_uint32array_from_arraybuffer_region",
         return_uint32array_from_arraybuffer_region,
     )?;
     cx.export_function("get_arraybuffer_byte_length", get_arraybuffer_byte_length)?;
+    cx.export_function("detach_array_buffer", detach_array_buffer)?;
+    cx.export_function("is_array_buffer_detached", is_array_buffer_detached)?;
     cx.export_function("detach_same_handle", detach_same_handle)?;
     cx.export_function("detach_and_escape", detach_and_escape)?;
     cx.export_function("detach_and_cast", detach_and_cast)?;
@@ -319,7 +382,14 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("get_property_with_prop", get_property_with_prop)?;
     cx.export_function("set_property_with_prop", set_property_with_prop)?;
     cx.export_function("call_methods_with_prop", call_methods_with_prop)?;
+    cx.export_function("call_method_with_tuple", call_method_with_tuple)?;
+    cx.export_function("call_method_with_slice", call_method_with_slice)?;
     cx.export_function("call_non_method_with_prop", call_non_method_with_prop)?;
+    cx.export_function("delete_property", delete_property)?;
+    cx.export_function("has_property", has_property)?;
+    cx.export_function("get_property_with_symbol", get_property_with_symbol)?;
+    cx.export_function("set_property_with_symbol", set_property_with_symbol)?;
+    cx.export_function("get_symbol_iterator", get_symbol_iterator)?;
 
     cx.export_function("create_date", create_date)?;
     cx.export_function("get_date_value", get_date_value)?;
@@ -343,22 +413,41 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("is_string", is_string)?;
     cx.export_function("is_undefined", is_undefined)?;
     cx.export_function("strict_equals", strict_equals)?;
+    cx.export_function("loose_equals", loose_equals)?;
+    cx.export_function("type_of", type_of)?;
+    cx.export_function("class_name", class_name)?;
 
     cx.export_function("new_error", new_error)?;
     cx.export_function("new_type_error", new_type_error)?;
     cx.export_function("new_range_error", new_range_error)?;
     cx.export_function("throw_error", throw_error)?;
+    cx.export_function("new_error_with_code", new_error_with_code)?;
+    cx.export_function("throw_error_with_code", throw_error_with_code)?;
+    cx.export_function("new_error_with_cause", new_error_with_cause)?;
     cx.export_function("downcast_error", downcast_error)?;
+    cx.export_function("downcast_or_throw_number", downcast_or_throw_number)?;
+    cx.export_function("throw_system_error", throw_system_error)?;
+    cx.export_function("build_system_error", build_system_error)?;
+    cx.export_function("error_kind", error_kind)?;
+    cx.export_function("is_type_or_range_error", is_type_or_range_error)?;
+    cx.export_function("capture_stack", capture_stack)?;
 
     cx.export_function("panic", panic)?;
     cx.export_function("panic_after_throw", panic_after_throw)?;
 
     cx.export_function("throw_and_catch", throw_and_catch)?;
     cx.export_function("call_and_catch", call_and_catch)?;
+    cx.export_function("call_and_catch_unwind", call_and_catch_unwind)?;
     cx.export_function("get_number_or_default", get_number_or_default)?;
     cx.export_function("assume_this_is_an_object", assume_this_is_an_object)?;
     cx.export_function("is_construct", is_construct)?;
     cx.export_function("caller_with_drop_callback", caller_with_drop_callback)?;
+    cx.export_function("make_counter_mut", make_counter_mut)?;
+    cx.export_function("make_reentrant_mut", make_reentrant_mut)?;
+    cx.export_function(
+        "is_exception_pending_after_call",
+        is_exception_pending_after_call,
+    )?;
 
     cx.export_function("count_called", {
         let n = std::cell::RefCell::new(0);
@@ -379,6 +468,8 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
 
     cx.export_function("person_new", person_new)?;
     cx.export_function("person_greet", person_greet)?;
+    cx.export_function("person_new_with_finalizer", person_new_with_finalizer)?;
+    cx.export_function("boxed_string_value", boxed_string_value)?;
     cx.export_function("ref_person_new", ref_person_new)?;
     cx.export_function("ref_person_greet", ref_person_greet)?;
     cx.export_function("ref_person_set_name", ref_person_set_name)?;
@@ -387,12 +478,19 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
 
     cx.export_function("useless_root", useless_root)?;
     cx.export_function("thread_callback", thread_callback)?;
+    cx.export_function("channel_call_root", channel_call_root)?;
     cx.export_function("multi_threaded_callback", multi_threaded_callback)?;
     cx.export_function("greeter_new", greeter_new)?;
     cx.export_function("greeter_greet", greeter_greet)?;
     cx.export_function("leak_channel", leak_channel)?;
+    cx.export_function("channel_reference_state", channel_reference_state)?;
     cx.export_function("drop_global_queue", drop_global_queue)?;
     cx.export_function("channel_join", channel_join)?;
+    cx.export_function("channel_send_and_wait", channel_send_and_wait)?;
+    cx.export_function(
+        "channel_send_and_wait_on_js_thread",
+        channel_send_and_wait_on_js_thread,
+    )?;
     cx.export_function("sum", sum)?;
     cx.export_function("sum_manual_promise", sum_manual_promise)?;
     cx.export_function("sum_rust_thread", sum_rust_thread)?;
@@ -411,6 +509,11 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("task_panic_execute_promise", task_panic_execute_promise)?;
     cx.export_function("task_panic_complete_promise", task_panic_complete_promise)?;
     cx.export_function("task_panic_throw_promise", task_panic_throw_promise)?;
+    cx.export_function("task_chain_promise", task_chain_promise)?;
+    cx.export_function("task_cancel_before_start", task_cancel_before_start)?;
+    cx.export_function("task_wait_for_cancel", task_wait_for_cancel)?;
+    cx.export_function("task_started", task_started)?;
+    cx.export_function("cancel_task", cancel_task)?;
     cx.export_function("deferred_settle_with_throw", deferred_settle_with_throw)?;
     cx.export_function("deferred_settle_with_panic", deferred_settle_with_panic)?;
     cx.export_function(
@@ -418,6 +521,7 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         deferred_settle_with_panic_throw,
     )?;
     cx.export_function("get_and_replace", js::workers::get_and_replace)?;
+    cx.export_function("try_get_and_replace", js::workers::try_get_and_replace)?;
     cx.export_function("get_or_init", js::workers::get_or_init)?;
     cx.export_function("get_or_init_clone", js::workers::get_or_init_clone)?;
     cx.export_function("get_or_init_thread_id", js::workers::get_or_init_thread_id)?;
@@ -425,18 +529,77 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("get_reentrant_value", js::workers::get_reentrant_value)?;
     cx.export_function("stash_global_object", js::workers::stash_global_object)?;
     cx.export_function("unstash_global_object", js::workers::unstash_global_object)?;
+    cx.export_function("replace_local_message", js::workers::replace_local_message)?;
+    cx.export_function("set_instance_message", js::workers::set_instance_message)?;
+    cx.export_function("get_instance_message", js::workers::get_instance_message)?;
+    cx.export_function(
+        "get_instance_data_as_string",
+        js::workers::get_instance_data_as_string,
+    )?;
     cx.export_function("reject_after", js::workers::reject_after)?;
     cx.export_function("box_channels", js::workers::box_channels)?;
+    cx.export_function("box_with_drop", js::workers::box_with_drop)?;
+    cx.export_function("box_with_drop_count", js::workers::box_with_drop_count)?;
 
     // Futures
     cx.export_function("lazy_async_add", js::futures::lazy_async_add)?;
+    cx.export_function("lazy_async_settled", js::futures::lazy_async_settled)?;
+    cx.export_function(
+        "replace_global_executor",
+        js::futures::replace_global_executor,
+    )?;
+    cx.export_function("use_counting_executor", js::futures::use_counting_executor)?;
     cx.export_function("lazy_async_sum", js::futures::lazy_async_sum)?;
+    cx.export_function("from_future_div", js::futures::from_future_div)?;
 
     // JsBigInt test suite
     cx.export_function("bigint_suite", js::bigint::bigint_suite)?;
 
+    // JsSymbol
+    cx.export_function("create_symbol", js::symbols::create_symbol)?;
+    cx.export_function("symbol_for", js::symbols::symbol_for)?;
+    cx.export_function("symbol_description", js::symbols::symbol_description)?;
+    cx.export_function("is_symbol", js::symbols::is_symbol)?;
+    cx.export_function("set_symbol_property", js::symbols::set_symbol_property)?;
+
+    // JsMap
+    cx.export_function("create_map_from_pairs", js::maps::create_map_from_pairs)?;
+    cx.export_function("map_get", js::maps::map_get)?;
+    cx.export_function("map_has", js::maps::map_has)?;
+    cx.export_function("map_delete", js::maps::map_delete)?;
+    cx.export_function("map_size", js::maps::map_size)?;
+    cx.export_function("map_entries", js::maps::map_entries)?;
+    cx.export_function("is_map", js::maps::is_map)?;
+
+    // JsSet
+    cx.export_function("create_set_from_array", js::sets::create_set_from_array)?;
+    cx.export_function("set_has", js::sets::set_has)?;
+    cx.export_function("set_delete", js::sets::set_delete)?;
+    cx.export_function("set_size", js::sets::set_size)?;
+    cx.export_function("set_values", js::sets::set_values)?;
+    cx.export_function("is_set", js::sets::is_set)?;
+
+    // JsRegExp
+    cx.export_function("create_regexp", js::regexps::create_regexp)?;
+    cx.export_function(
+        "regexp_source_and_flags",
+        js::regexps::regexp_source_and_flags,
+    )?;
+    cx.export_function("regexp_test", js::regexps::regexp_test)?;
+    cx.export_function("regexp_exec", js::regexps::regexp_exec)?;
+    cx.export_function("is_regexp", js::regexps::is_regexp)?;
+
     // Extractors
     cx.export_function("extract_values", js::extract::extract_values)?;
+    cx.export_function(
+        "extract_optional_trailing",
+        js::extract::extract_optional_trailing,
+    )?;
+    cx.export_function("extract_rest_join", js::extract::extract_rest_join)?;
+    cx.export_function(
+        "extract_args_with_this",
+        js::extract::extract_args_with_this,
+    )?;
     cx.export_function("extract_buffer_sum", js::extract::extract_buffer_sum)?;
     cx.export_function("extract_json_sum", js::extract::extract_json_sum)?;
     cx.export_function(